@@ -0,0 +1,148 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+use mutstr::mutstr;
+
+// Counts every allocation and reallocation of the process.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// Runs `f` and returns the number of allocations and reallocations it made.
+fn bench<F: FnOnce()>(name: &str, f: F) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    f();
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    println!("{name}: {elapsed:?}, {allocations} allocations");
+    allocations
+}
+
+const FRAGMENTS: usize = 10_000;
+
+fn push_fragments() {
+    let allocations = bench("push fragments", || {
+        let mut result = mutstr::default();
+        for _ in 0..FRAGMENTS {
+            result.push(black_box("ab"));
+        }
+        black_box(result);
+    });
+    assert!(allocations <= (FRAGMENTS * 2).ilog2() as usize);
+}
+
+fn push_all_exact_fragments() {
+    let fragments = vec!["ab"; FRAGMENTS];
+    let allocations = bench("push_all_exact fragments", || {
+        let mut result = mutstr::default();
+        result.push_all_exact(black_box(&fragments));
+        black_box(result);
+    });
+    assert_eq!(allocations, 1);
+}
+
+const CHARS: usize = 100_000;
+
+fn push_chars() {
+    let mutstr_allocations = bench("mutstr push chars", || {
+        let mut result = mutstr::default();
+        for _ in 0..CHARS {
+            result.push(black_box("a"));
+        }
+        black_box(result);
+    });
+    let string_allocations = bench("String push chars", || {
+        let mut result = String::new();
+        for _ in 0..CHARS {
+            result.push(black_box('a'));
+        }
+        black_box(result);
+    });
+    // The growth has to be amortized, a linear number of reallocations is a regression.
+    assert!(mutstr_allocations <= CHARS.ilog2() as usize);
+    assert!(string_allocations <= CHARS.ilog2() as usize);
+}
+
+fn with_capacity_push_chars() {
+    let mutstr_allocations = bench("mutstr with_capacity push chars", || {
+        let mut result = mutstr::with_capacity(CHARS);
+        for _ in 0..CHARS {
+            result.push(black_box("a"));
+        }
+        black_box(result);
+    });
+    let string_allocations = bench("String with_capacity push chars", || {
+        let mut result = String::with_capacity(CHARS);
+        for _ in 0..CHARS {
+            result.push(black_box('a'));
+        }
+        black_box(result);
+    });
+    assert_eq!((mutstr_allocations, string_allocations), (1, 1));
+}
+
+const REPLACEMENTS: usize = 100_000;
+
+fn replace_with_reuse() {
+    let mut result = mutstr::with_capacity(8);
+    let allocations = bench("replace_with reuse", || {
+        for i in 0..REPLACEMENTS {
+            result.replace_with(black_box(if i % 2 == 0 { "abc" } else { "abcde" }));
+        }
+    });
+    black_box(result);
+    assert_eq!(allocations, 0);
+}
+
+const SLICES: usize = 1_000_000;
+
+fn index_slices() {
+    let source = mutstr::from("hello my friend :)");
+    bench("index slices", || {
+        let mut len = 0;
+        for i in 0..SLICES {
+            len += source[black_box(i % 8)..black_box(i % 8 + 8)].len();
+        }
+        black_box(len);
+    });
+    bench("get_unchecked slices", || {
+        let mut len = 0;
+        for i in 0..SLICES {
+            len += unsafe { source.get_unchecked(black_box(i % 8)..black_box(i % 8 + 8)) }.len();
+        }
+        black_box(len);
+    });
+}
+
+fn main() {
+    push_fragments();
+    push_all_exact_fragments();
+    push_chars();
+    with_capacity_push_chars();
+    replace_with_reuse();
+    index_slices();
+}
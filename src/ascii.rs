@@ -0,0 +1,132 @@
+use crate::mutstr;
+
+/// A mutable borrow of a [`mutstr`] that only contains ASCII bytes, created by [`mutstr::try_as_ascii`].
+///
+/// Every permutation of ASCII bytes is valid UTF-8, so the bytes can be mutated without `unsafe`.
+/// Writing a byte is checked to stay in the ASCII range, which keeps the `mutstr` valid.
+///
+/// ### Example
+/// ```
+/// use mutstr::mutstr;
+/// let mut result = mutstr::from("hello");
+/// let mut ascii = result.try_as_ascii().unwrap();
+/// ascii.set(0, b'j');
+/// ascii.swap(1, 4);
+/// assert_eq!(result.as_str(), "jolle");
+/// ```
+pub struct AsciiMutStr<'a> {
+    value: &'a mut mutstr,
+}
+
+impl<'a> AsciiMutStr<'a> {
+    #[inline(always)]
+    pub(crate) fn new(value: &'a mut mutstr) -> Self {
+        Self { value }
+    }
+
+    #[inline(always)]
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        // Safety: Every method only permutes bytes or writes ASCII bytes.
+        unsafe { self.value.as_bytes_mut() }
+    }
+
+    /// Get the borrowed data as `&[u8]`.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.value.as_bytes()
+    }
+
+    /// Get the borrowed data as `&str`.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+
+    /// Get the length of the borrowed bytes.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.value.size()
+    }
+
+    /// Short version of `self.len() == 0`.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Overwrites the byte at `index` with `byte`.
+    ///
+    /// ### Panics
+    /// Panics if `index` is out of bounds or `byte` is not ASCII.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.try_as_ascii().unwrap().set(1, b'x');
+    /// assert_eq!(result.as_str(), "axc");
+    /// ```
+    pub fn set(&mut self, index: usize, byte: u8) {
+        assert!(byte.is_ascii(), "byte {byte:#04x} is not ASCII");
+        self.bytes_mut()[index] = byte;
+    }
+
+    /// Swaps the bytes at `a` and `b`.
+    ///
+    /// ### Panics
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.try_as_ascii().unwrap().swap(0, 2);
+    /// assert_eq!(result.as_str(), "cba");
+    /// ```
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.bytes_mut().swap(a, b);
+    }
+
+    /// Reverses the order of the bytes.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.try_as_ascii().unwrap().reverse();
+    /// assert_eq!(result.as_str(), "cba");
+    /// ```
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.bytes_mut().reverse();
+    }
+
+    /// Converts all bytes to their ASCII lower case equivalent.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("ABC");
+    /// result.try_as_ascii().unwrap().make_ascii_lowercase();
+    /// assert_eq!(result.as_str(), "abc");
+    /// ```
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.bytes_mut().make_ascii_lowercase();
+    }
+
+    /// Converts all bytes to their ASCII upper case equivalent.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.try_as_ascii().unwrap().make_ascii_uppercase();
+    /// assert_eq!(result.as_str(), "ABC");
+    /// ```
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.bytes_mut().make_ascii_uppercase();
+    }
+}
@@ -0,0 +1,82 @@
+use std::ops;
+
+use crate::mutstr;
+
+/// The byte offsets of all chars of a [`mutstr`], created by [`mutstr::char_index`].
+///
+/// Maps char indices to byte offsets in `O(1)`. The `mutstr` is borrowed, so it can't be mutated
+/// while the offsets are in use.
+///
+/// ### Example
+/// ```
+/// use mutstr::mutstr;
+/// let source = mutstr::from("a😀b");
+/// let index = source.char_index();
+/// assert_eq!(index.byte_offset(2), Some(5));
+/// assert_eq!(index.slice(1..3), Some("😀b"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CharIndex<'a> {
+    source: &'a mutstr,
+    // The offset of every char, followed by the size of the source.
+    offsets: Vec<usize>,
+}
+
+impl<'a> CharIndex<'a> {
+    pub(crate) fn new(source: &'a mutstr) -> Self {
+        let offsets = source
+            .as_str()
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain(std::iter::once(source.size()))
+            .collect();
+        Self { source, offsets }
+    }
+
+    /// Get the number of chars.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Short version of `self.len() == 0`.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the byte offset of the char at `index`.
+    ///
+    /// **Notice:** _An `index` equal to `self.len()` returns the size of the `mutstr`_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let source = mutstr::from("a😀b");
+    /// let index = source.char_index();
+    /// assert_eq!(index.byte_offset(1), Some(1));
+    /// assert_eq!(index.byte_offset(3), Some(6));
+    /// assert_eq!(index.byte_offset(4), None);
+    /// ```
+    #[inline]
+    pub fn byte_offset(&self, index: usize) -> Option<usize> {
+        self.offsets.get(index).copied()
+    }
+
+    /// Get the chars in the char range `range`.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let source = mutstr::from("a😀b");
+    /// let index = source.char_index();
+    /// assert_eq!(index.slice(0..2), Some("a😀"));
+    /// assert_eq!(index.slice(2..4), None);
+    /// ```
+    #[inline]
+    pub fn slice(&self, range: ops::Range<usize>) -> Option<&'a str> {
+        let start = self.byte_offset(range.start)?;
+        let end = self.byte_offset(range.end)?;
+        self.source.as_str().get(start..end)
+    }
+}
@@ -0,0 +1,180 @@
+use std::{fmt, sync::Arc};
+
+use crate::mutstr;
+
+/// A [`mutstr`] with copy-on-write semantics.
+///
+/// Cloning only shares the data through an `Arc`. The data is copied on the first mutation of a shared value,
+/// which makes clone-heavy workloads that rarely mutate cheap.
+///
+/// ### Example
+/// ```
+/// use ::mutstr::{mutstr, CowMutStr};
+/// let first = CowMutStr::from(mutstr::from("friend"));
+/// let mut second = first.clone();
+/// assert!(first.is_shared());
+///
+/// second.push(" :)"); // Copy
+/// assert_eq!(first.as_str(), "friend");
+/// assert_eq!(second.as_str(), "friend :)");
+/// ```
+#[derive(Clone, Default)]
+pub struct CowMutStr {
+    inner: Arc<mutstr>,
+}
+
+impl CowMutStr {
+    /// Wraps `value` without copying it.
+    #[inline]
+    pub fn new(value: mutstr) -> Self {
+        Self {
+            inner: Arc::new(value),
+        }
+    }
+
+    /// Get the wrapped `mutstr`.
+    #[inline(always)]
+    pub fn get(&self) -> &mutstr {
+        &self.inner
+    }
+
+    /// Get the data as `&str`.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns `true` if the data is shared with a clone.
+    #[inline]
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.inner) > 1
+    }
+
+    /// Get the wrapped `mutstr` mutably, copying the data first if it is shared.
+    ///
+    /// ### Example
+    /// ```
+    /// use ::mutstr::{mutstr, CowMutStr};
+    /// let first = CowMutStr::from(mutstr::from("abc"));
+    /// let mut second = first.clone();
+    /// second.make_mut().replace_with("123");
+    /// assert_eq!(first.as_str(), "abc");
+    /// assert_eq!(second.as_str(), "123");
+    /// ```
+    #[inline]
+    pub fn make_mut(&mut self) -> &mut mutstr {
+        Arc::make_mut(&mut self.inner)
+    }
+
+    /// Like [`mutstr::push`], but copies the data first if it is shared.
+    #[inline]
+    pub fn push<T>(&mut self, value: T)
+    where
+        T: AsRef<[u8]>,
+    {
+        self.make_mut().push(value);
+    }
+
+    /// Like [`mutstr::replace_with`], but copies the data first if it is shared.
+    #[inline]
+    pub fn replace_with<T>(&mut self, value: T)
+    where
+        T: AsRef<[u8]>,
+    {
+        self.make_mut().replace_with(value);
+    }
+
+    /// Like [`mutstr::clear`], but a shared value is replaced by an empty one instead of copied.
+    pub fn clear(&mut self) {
+        match Arc::get_mut(&mut self.inner) {
+            Some(value) => value.clear(),
+            None => self.inner = Arc::default(),
+        }
+    }
+
+    /// Unwraps the `mutstr`, copying the data if it is shared.
+    #[inline]
+    pub fn into_mutstr(self) -> mutstr {
+        Arc::try_unwrap(self.inner).unwrap_or_else(|shared| (*shared).clone())
+    }
+}
+
+impl From<mutstr> for CowMutStr {
+    #[inline]
+    fn from(value: mutstr) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for CowMutStr {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self::new(mutstr::from(value))
+    }
+}
+
+impl AsRef<[u8]> for CowMutStr {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl AsRef<str> for CowMutStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CowMutStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Debug for CowMutStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CowMutStr").field("inner", &self.inner).finish()
+    }
+}
+
+#[cfg(test)]
+mod implementations {
+    use super::CowMutStr;
+    use crate::allocations;
+
+    #[test]
+    fn clone() {
+        let first = CowMutStr::from("abc123");
+        let before = allocations();
+        let mut second = first.clone();
+        assert_eq!(allocations(), before);
+        assert_eq!(first.get().ptr(), second.get().ptr());
+
+        second.push("!");
+        assert!(allocations() > before);
+        assert_eq!(first.as_str(), "abc123");
+        assert_eq!(second.as_str(), "abc123!");
+    }
+
+    #[test]
+    fn clear() {
+        let first = CowMutStr::from("abc123");
+        let mut second = first.clone();
+        let before = allocations();
+        second.clear();
+        assert_eq!(allocations(), before);
+        assert_eq!(first.as_str(), "abc123");
+        assert_eq!(second.as_str(), "");
+    }
+
+    #[test]
+    fn into_mutstr() {
+        let first = CowMutStr::from("abc123");
+        let ptr = first.get().ptr();
+        assert_eq!(first.into_mutstr().ptr(), ptr);
+    }
+}
@@ -0,0 +1,137 @@
+use std::{error, fmt};
+
+/// The error returned by [`mutstr::pad_with_pattern`](crate::mutstr::pad_with_pattern)
+/// if the pattern can't fill the missing bytes.
+///
+/// ### Example
+/// ```
+/// use ::mutstr::{mutstr, PadError};
+/// let mut result = mutstr::from("x");
+/// assert_eq!(result.pad_with_pattern("", 3), Err(PadError::EmptyPattern { remaining: 2 }));
+/// assert_eq!(result.pad_with_pattern("😀", 3), Err(PadError::SplitsChar { remaining: 2 }));
+/// assert_eq!(result.as_str(), "x");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadError {
+    /// The pattern is empty, so no number of copies can fill the missing bytes.
+    EmptyPattern { remaining: usize },
+    /// The last, partial copy of the pattern would split a char.
+    SplitsChar { remaining: usize },
+}
+
+impl PadError {
+    /// The number of bytes that were left to fill, by the empty pattern or by the partial copy.
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        match self {
+            Self::EmptyPattern { remaining } | Self::SplitsChar { remaining } => *remaining,
+        }
+    }
+}
+
+impl fmt::Display for PadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyPattern { remaining } => {
+                write!(f, "an empty pattern can't fill the remaining {} bytes", remaining)
+            }
+            Self::SplitsChar { remaining } => write!(
+                f,
+                "the pattern can't fill the remaining {} bytes without splitting a char",
+                remaining
+            ),
+        }
+    }
+}
+
+impl error::Error for PadError {}
+
+/// The error returned by [`mutstr::from_utf8`](crate::mutstr::from_utf8) if the bytes are not valid UTF-8.
+///
+/// Like `std::string::FromUtf8Error`, the original bytes can be taken back.
+///
+/// ### Example
+/// ```
+/// use mutstr::mutstr;
+/// let error = mutstr::from_utf8(vec![0x61, 0xff]).unwrap_err();
+/// assert_eq!(error.utf8_error().valid_up_to(), 1);
+/// assert_eq!(error.into_bytes(), [0x61, 0xff]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromUtf8Error {
+    pub(crate) bytes: Vec<u8>,
+    pub(crate) error: std::str::Utf8Error,
+}
+
+impl FromUtf8Error {
+    /// Get the bytes that were passed to `mutstr::from_utf8()`.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the bytes that were passed to `mutstr::from_utf8()`.
+    #[inline(always)]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Get the details of the invalid UTF-8.
+    #[inline(always)]
+    pub fn utf8_error(&self) -> std::str::Utf8Error {
+        self.error
+    }
+}
+
+impl fmt::Display for FromUtf8Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl error::Error for FromUtf8Error {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The error returned by [`mutstr::from_hex`](crate::mutstr::from_hex).
+///
+/// ### Example
+/// ```
+/// use ::mutstr::{mutstr, HexError};
+/// assert_eq!(mutstr::from_hex("616").unwrap_err(), HexError::OddLength);
+/// assert_eq!(mutstr::from_hex("6x").unwrap_err(), HexError::InvalidDigit { index: 1 });
+/// assert!(matches!(mutstr::from_hex("ff").unwrap_err(), HexError::InvalidUtf8(_)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexError {
+    /// The number of hex digits is odd, so the last byte is incomplete.
+    OddLength,
+    /// The byte at `index` is not a hex digit.
+    InvalidDigit { index: usize },
+    /// The decoded bytes are not valid UTF-8.
+    InvalidUtf8(std::str::Utf8Error),
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength => write!(f, "odd number of hex digits"),
+            Self::InvalidDigit { index } => write!(f, "invalid hex digit at index {}", index),
+            Self::InvalidUtf8(error) => write!(f, "decoded bytes are not valid UTF-8: {}", error),
+        }
+    }
+}
+
+impl error::Error for HexError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidUtf8(error) => Some(error),
+            _ => None,
+        }
+    }
+}
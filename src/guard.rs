@@ -0,0 +1,86 @@
+use std::{fmt, ops};
+
+use crate::mutstr;
+
+/// A shared borrow of the data of a [`mutstr`], created by [`mutstr::borrow_str`].
+///
+/// As long as the guard is alive, the `mutstr` can neither be mutated nor moved,
+/// so the pointer returned by [`StrGuard::as_ptr`] stays valid. This makes the guard
+/// useful for handing the data to FFI code, where the borrow checker can't follow the pointer.
+///
+/// ### Example
+/// ```
+/// use mutstr::mutstr;
+/// let result = mutstr::from("abc");
+/// let guard = result.borrow_str();
+/// assert_eq!(&*guard, "abc");
+/// assert_eq!(guard.len(), 3);
+/// ```
+///
+/// Mutating the `mutstr` while the guard is alive is rejected:
+/// ```compile_fail
+/// use mutstr::mutstr;
+/// let mut result = mutstr::from("abc");
+/// let guard = result.borrow_str();
+/// result.push("def");
+/// drop(guard);
+/// ```
+pub struct StrGuard<'a> {
+    value: &'a mutstr,
+}
+
+impl<'a> StrGuard<'a> {
+    #[inline(always)]
+    pub(crate) fn new(value: &'a mutstr) -> Self {
+        Self { value }
+    }
+
+    /// The raw pointer of the borrowed data.
+    ///
+    /// **Notice:** _The pointer is valid for `self.len()` bytes as long as the guard is alive_.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.value.ptr()
+    }
+
+    /// Get the length of the borrowed bytes.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.value.size()
+    }
+
+    /// Short version of `self.len() == 0`.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the borrowed data as `&str`.
+    #[inline(always)]
+    pub fn as_str(&self) -> &'a str {
+        self.value.as_str()
+    }
+}
+
+impl ops::Deref for StrGuard<'_> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StrGuard<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Debug for StrGuard<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StrGuard").field("_ptr", &self.as_ptr()).finish()
+    }
+}
@@ -0,0 +1,80 @@
+use std::iter::FusedIterator;
+
+/// An iterator over the whitespace separated parts of a [`mutstr`](crate::mutstr) and their byte offsets,
+/// created by [`mutstr::split_whitespace_indices`](crate::mutstr::split_whitespace_indices).
+#[derive(Debug, Clone)]
+pub struct SplitWhitespaceIndices<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> SplitWhitespaceIndices<'a> {
+    #[inline(always)]
+    pub(crate) fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            position: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for SplitWhitespaceIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.source[self.position..];
+        let Some(offset) = rest.find(|c: char| !c.is_whitespace()) else {
+            self.position = self.source.len();
+            return None;
+        };
+
+        let start = self.position + offset;
+        let end = self.source[start..]
+            .find(char::is_whitespace)
+            .map_or(self.source.len(), |len| start + len);
+        self.position = end;
+        Some((start, &self.source[start..end]))
+    }
+}
+
+impl FusedIterator for SplitWhitespaceIndices<'_> {}
+
+/// An iterator over chunks of a [`mutstr`](crate::mutstr) that never split a char,
+/// created by [`mutstr::byte_chunks`](crate::mutstr::byte_chunks).
+#[derive(Debug, Clone)]
+pub struct ByteChunks<'a> {
+    rest: &'a str,
+    max_bytes: usize,
+}
+
+impl<'a> ByteChunks<'a> {
+    #[inline(always)]
+    pub(crate) fn new(source: &'a str, max_bytes: usize) -> Self {
+        Self {
+            rest: source,
+            max_bytes,
+        }
+    }
+}
+
+impl<'a> Iterator for ByteChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.rest.chars().next()?;
+        let mut end = self.max_bytes.min(self.rest.len());
+        while !self.rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            // The char doesn't fit, so it gets a chunk of its own.
+            end = first.len_utf8();
+        }
+
+        let (chunk, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(chunk)
+    }
+}
+
+impl FusedIterator for ByteChunks<'_> {}
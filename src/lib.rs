@@ -18,11 +18,79 @@
 
 use std::{alloc, fmt, ops};
 
+mod ascii;
+pub use ascii::AsciiMutStr;
+
+mod char_index;
+pub use char_index::CharIndex;
+
+mod cow;
+pub use cow::CowMutStr;
+
+mod error;
+pub use error::{FromUtf8Error, HexError, PadError};
+
+mod guard;
+pub use guard::StrGuard;
+
+mod iter;
+pub use iter::{ByteChunks, SplitWhitespaceIndices};
+
+mod prehashed;
+pub use prehashed::PreHashed;
+
+mod scanner;
+pub use scanner::Scanner;
+
+#[cfg(test)]
+thread_local! {
+    // Counts the allocations and reallocations of `MutStrPtr` on the current thread.
+    static ALLOCATIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+#[cfg(test)]
+pub(crate) fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+#[inline(always)]
+fn count_allocation() {
+    #[cfg(test)]
+    ALLOCATIONS.with(|count| count.set(count.get() + 1));
+}
+
+// The size of the header in front of the bytes, which stores the capacity.
+const HEADER_SIZE: usize = std::mem::size_of::<usize>();
+
 // The first value is the pointer, the second the length of bytes.
+// The capacity is stored in a header in front of the bytes, so `mutstr` keeps using 16 bytes.
+// A capacity of `0` never owns an allocation, because allocating `0` bytes is undefined behavior.
 struct MutStrPtr(*mut u8, usize);
 unsafe impl Send for MutStrPtr {}
 unsafe impl Sync for MutStrPtr {}
 impl MutStrPtr {
+    #[inline(always)]
+    fn empty() -> Self {
+        Self(std::ptr::NonNull::dangling().as_ptr(), 0)
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        if capacity == 0 {
+            return Self::empty();
+        }
+
+        let layout = Self::layout_for(capacity);
+        count_allocation();
+        unsafe {
+            let header = alloc::alloc(layout);
+            if header.is_null() {
+                alloc::handle_alloc_error(layout);
+            }
+            (header as *mut usize).write(capacity);
+            Self(header.add(HEADER_SIZE), 0)
+        }
+    }
+
     #[inline(always)]
     fn raw(&self) -> *mut u8 {
         self.0
@@ -33,31 +101,141 @@ impl MutStrPtr {
         self.1
     }
 
+    #[inline(always)]
+    fn is_allocated(&self) -> bool {
+        // Allocated bytes always start behind the aligned header, never at a dangling address.
+        self.0 != std::ptr::NonNull::dangling().as_ptr()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        if self.is_allocated() {
+            unsafe { (self.0.sub(HEADER_SIZE) as *const usize).read() }
+        } else {
+            0
+        }
+    }
+
     #[inline(always)]
     fn layout(&self) -> alloc::Layout {
-        unsafe { alloc::Layout::from_size_align_unchecked(self.size(), 1) }
+        unsafe { alloc::Layout::from_size_align_unchecked(self.capacity(), 1) }
+    }
+
+    // The layout of the whole allocation, including the header.
+    fn layout_for(capacity: usize) -> alloc::Layout {
+        HEADER_SIZE
+            .checked_add(capacity)
+            .and_then(|size| alloc::Layout::from_size_align(size, std::mem::align_of::<usize>()).ok())
+            .expect("capacity overflow")
     }
 
-    fn realloc(&mut self, new_size: usize) {
+    // Reallocates to exactly `new_capacity` bytes, the length is cut off if it doesn't fit.
+    fn set_capacity(&mut self, new_capacity: usize) {
+        let old_capacity = self.capacity();
+        if new_capacity == old_capacity {
+            return;
+        }
+        if old_capacity == 0 {
+            // Nothing to free, the old value doesn't own an allocation.
+            *self = Self::with_capacity(new_capacity);
+            return;
+        }
+        if new_capacity == 0 {
+            self.free();
+            return;
+        }
+
+        let new_layout = Self::layout_for(new_capacity);
+        count_allocation();
         unsafe {
-            let old_layout = self.layout();
-            self.0 = alloc::realloc(self.raw(), old_layout, new_size);
+            let old_header = self.0.sub(HEADER_SIZE);
+            let header = alloc::realloc(old_header, Self::layout_for(old_capacity), new_layout.size());
+            if header.is_null() {
+                alloc::handle_alloc_error(new_layout);
+            }
+            (header as *mut usize).write(new_capacity);
+            self.0 = header.add(HEADER_SIZE);
         };
+        self.1 = self.1.min(new_capacity);
+    }
+
+    // The first `new_size` bytes must be initialized and fit into the capacity.
+    #[inline(always)]
+    unsafe fn set_size(&mut self, new_size: usize) {
+        debug_assert!(new_size <= self.capacity());
         self.1 = new_size;
     }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        if additional > self.capacity() - self.size() {
+            self.grow(additional);
+        }
+    }
+
+    // Grows the capacity at least to the double, so a series of pushes only reallocates `O(log n)` times.
+    #[cold]
+    fn grow(&mut self, additional: usize) {
+        let required = self.size().checked_add(additional).expect("capacity overflow");
+        self.set_capacity(required.max(self.capacity().saturating_mul(2)).max(8));
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        let required = self.size().checked_add(additional).expect("capacity overflow");
+        if required > self.capacity() {
+            self.set_capacity(required);
+        }
+    }
+
+    // Writes `bytes` at the end, growing the capacity if necessary.
+    #[inline]
+    fn extend(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+        unsafe {
+            std::ptr::copy(bytes.as_ptr(), self.raw().add(self.size()), bytes.len());
+            self.set_size(self.size() + bytes.len());
+        };
+    }
+
+    // Replaces the bytes in `range` with `bytes` and moves the bytes behind it, growing the capacity if necessary.
+    fn splice(&mut self, range: ops::Range<usize>, bytes: &[u8]) {
+        debug_assert!(range.start <= range.end && range.end <= self.size());
+        let tail = self.size() - range.end;
+        if bytes.len() > range.len() {
+            self.reserve(bytes.len() - range.len());
+        }
+        unsafe {
+            let start = self.raw().add(range.start);
+            std::ptr::copy(start.add(range.len()), start.add(bytes.len()), tail);
+            std::ptr::copy(bytes.as_ptr(), start, bytes.len());
+            self.set_size(range.start + bytes.len() + tail);
+        };
+    }
+
+    fn free(&mut self) {
+        if self.is_allocated() {
+            unsafe {
+                alloc::dealloc(self.0.sub(HEADER_SIZE), Self::layout_for(self.capacity()));
+            };
+            self.0 = std::ptr::NonNull::dangling().as_ptr();
+            self.1 = 0;
+        }
+    }
 }
 
 #[cfg(feature = "drop")]
 impl Drop for MutStrPtr {
+    #[inline]
     fn drop(&mut self) {
-        if self.size() != 0 {
-            unsafe {
-                alloc::dealloc(self.raw(), self.layout());
-            };
-        }
+        self.free();
     }
 }
 
+// Frees the raw parts of a `mutstr` handed out by `mutstr::into_ffi`.
+unsafe extern "C" fn free_ffi(ptr: *mut u8, size: usize) {
+    MutStrPtr(ptr, size).free();
+}
+
 /// ### Example
 /// ```
 /// use mutstr::mutstr;
@@ -71,7 +249,216 @@ pub struct mutstr {
 }
 
 impl mutstr {
-    /// The raw pointer of the allocated heap.
+    /// Creates an empty `mutstr` which can store `capacity` bytes without reallocating.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::with_capacity(8);
+    /// assert_eq!(result.capacity(), 8);
+    /// result.push("abc");
+    /// assert_eq!(result.capacity(), 8);
+    /// ```
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            _ptr: MutStrPtr::with_capacity(capacity),
+        }
+    }
+
+    /// Creates a `mutstr` from `bytes`, if they are valid UTF-8.
+    ///
+    /// **Notice:** _Unlike `String::from_utf8()`, this is not zero-copy. On success the bytes are copied once
+    /// into a new allocation and the `Vec` is freed, because `mutstr` stores its capacity in front of the bytes
+    /// and can't adopt the allocation of a `Vec`. On failure nothing is allocated_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from_utf8(vec![0x61, 0x62]).unwrap();
+    /// assert_eq!(result.as_str(), "ab");
+    ///
+    /// let error = mutstr::from_utf8(vec![0x61, 0xff]).unwrap_err();
+    /// assert_eq!(error.into_bytes(), [0x61, 0xff]);
+    /// ```
+    pub fn from_utf8(bytes: Vec<u8>) -> Result<Self, FromUtf8Error> {
+        match std::str::from_utf8(&bytes) {
+            Ok(value) => Ok(Self::from(value)),
+            Err(error) => Err(FromUtf8Error { bytes, error }),
+        }
+    }
+
+    /// Creates a `mutstr` by decoding pairs of hex digits into bytes, which must be valid UTF-8.
+    ///
+    /// Upper- and lowercase digits are accepted. The hex digits are checked before anything is allocated.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from_hex("6162").unwrap();
+    /// assert_eq!(result.as_str(), "ab");
+    /// assert!(mutstr::from_hex("616").is_err());
+    /// assert!(mutstr::from_hex("6g").is_err());
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, HexError> {
+        let digits = hex.as_bytes();
+        if !digits.len().is_multiple_of(2) {
+            return Err(HexError::OddLength);
+        }
+        if let Some(index) = digits.iter().position(|digit| !digit.is_ascii_hexdigit()) {
+            return Err(HexError::InvalidDigit { index });
+        }
+
+        let value = |digit: u8| (digit as char).to_digit(16).unwrap_or_default() as u8;
+        let mut result = Self::with_capacity(digits.len() / 2);
+        for pair in digits.chunks_exact(2) {
+            result._ptr.extend(&[value(pair[0]) << 4 | value(pair[1])]);
+        }
+        if let Err(error) = std::str::from_utf8(result.as_bytes()) {
+            result._ptr.free();
+            return Err(HexError::InvalidUtf8(error));
+        }
+        Ok(result)
+    }
+
+    /// Creates a `mutstr` from chars, allocating the exact number of bytes they need at once.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from_chars_vec(vec!['a', '😀', 'b']);
+    /// assert_eq!(result.as_str(), "a😀b");
+    /// assert_eq!(result.capacity(), 6);
+    /// ```
+    pub fn from_chars_vec(chars: Vec<char>) -> Self {
+        let size = chars.iter().map(|ch| ch.len_utf8()).sum();
+        let mut result = Self::with_capacity(size);
+        let mut buffer = [0; 4];
+        for ch in chars {
+            result._ptr.extend(ch.encode_utf8(&mut buffer).as_bytes());
+        }
+        result
+    }
+
+    /// Creates a `mutstr` from a pointer and a length returned by [`mutstr::into_raw_parts`].
+    ///
+    /// ### Safety
+    /// `ptr` and `size` must be returned by `mutstr::into_raw_parts` and must not be used by another `mutstr`.
+    /// The bytes may have been changed in the meantime. If they are not valid UTF-8 anymore,
+    /// only use `as_bytes()` and `try_as_str()` until the data is valid again.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let (ptr, size) = mutstr::from("abc").into_raw_parts();
+    /// let result = unsafe { mutstr::from_raw_parts(ptr, size) };
+    /// assert_eq!(result.as_str(), "abc");
+    /// ```
+    #[inline(always)]
+    pub unsafe fn from_raw_parts(ptr: *mut u8, size: usize) -> Self {
+        Self {
+            _ptr: MutStrPtr(ptr, size),
+        }
+    }
+
+    /// Returns the raw pointer and the length of bytes, without freeing the allocated heap.
+    ///
+    /// **Notice:** _Use `mutstr::from_raw_parts()` to free the memory again_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let (ptr, size) = mutstr::from("abc").into_raw_parts();
+    /// assert_eq!(size, 3);
+    /// drop(unsafe { mutstr::from_raw_parts(ptr, size) });
+    /// ```
+    #[inline]
+    pub fn into_raw_parts(self) -> (*mut u8, usize) {
+        let value = std::mem::ManuallyDrop::new(self);
+        (value._ptr.raw(), value.size())
+    }
+
+    /// Returns the raw pointer, the length of bytes and a function to free them, to pass the ownership to C.
+    ///
+    /// **Notice:** _The function has to be called exactly once with the returned pointer and length,
+    /// so the memory is freed with the allocator and layout of this crate_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let (ptr, size, free) = mutstr::from("abc").into_ffi();
+    /// assert_eq!(size, 3);
+    /// unsafe {
+    ///     free(ptr, size);
+    /// };
+    /// ```
+    #[inline]
+    pub fn into_ffi(self) -> (*mut u8, usize, unsafe extern "C" fn(*mut u8, usize)) {
+        let (ptr, size) = self.into_raw_parts();
+        (ptr, size, free_ffi)
+    }
+
+    /// Creates a `CString` from a copy of the data, for C APIs that expect nul terminated strings.
+    ///
+    /// Returns an error if the data contains a nul byte, because C would cut it off there.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("abc").to_cstring().unwrap();
+    /// assert_eq!(result.as_bytes_with_nul(), b"abc\0");
+    /// assert!(mutstr::from("a\0c").to_cstring().is_err());
+    /// ```
+    #[inline]
+    pub fn to_cstring(&self) -> Result<std::ffi::CString, std::ffi::NulError> {
+        std::ffi::CString::new(self.as_bytes())
+    }
+
+    /// Overwrites `buf` with the bytes followed by a nul byte, reusing the capacity of `buf`.
+    ///
+    /// **Notice:** _Unlike `to_cstring()`, nul bytes in the data are not rejected_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut buf = Vec::new();
+    /// mutstr::from("abc").as_bytes_with_nul_into(&mut buf);
+    /// assert_eq!(buf, b"abc\0");
+    /// ```
+    pub fn as_bytes_with_nul_into(&self, buf: &mut Vec<u8>) {
+        buf.clear();
+        buf.reserve(self.size() + 1);
+        buf.extend_from_slice(self.as_bytes());
+        buf.push(0);
+    }
+
+    /// Converts the data into ASCII bytes, or returns `self` unchanged if any byte isn't ASCII.
+    ///
+    /// **Notice:** _The bytes are copied once, like for `mutstr::from_utf8()`_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("cafe").into_ascii_bytes();
+    /// assert_eq!(result.unwrap(), b"cafe");
+    ///
+    /// let result = mutstr::from("café").into_ascii_bytes();
+    /// assert_eq!(result.unwrap_err().as_str(), "café");
+    /// ```
+    pub fn into_ascii_bytes(self) -> Result<Vec<u8>, mutstr> {
+        if self.as_bytes().is_ascii() {
+            Ok(self.as_bytes().to_vec())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// The raw pointer of the allocated bytes.
+    ///
+    /// ### Warning
+    /// The pointer doesn't point to the start of the allocation, because the capacity is stored in front of the bytes.
+    /// Never pass it to `std::alloc::dealloc()`, that is undefined behavior. Use `clear_and_dealloc()` to free the memory,
+    /// or `into_raw_parts()` and `mutstr::from_raw_parts()` to pass the ownership around.
     ///
     /// ### Example
     /// ```
@@ -84,7 +471,12 @@ impl mutstr {
         self._ptr.raw() as *const u8
     }
 
-    /// The raw pointer of the allocated heap.
+    /// The raw pointer of the allocated bytes.
+    ///
+    /// ### Warning
+    /// The pointer doesn't point to the start of the allocation, because the capacity is stored in front of the bytes.
+    /// Never pass it to `std::alloc::dealloc()`, that is undefined behavior. Use `clear_and_dealloc()` to free the memory,
+    /// or `into_raw_parts()` and `mutstr::from_raw_parts()` to pass the ownership around.
     ///
     /// ### Example
     /// ```
@@ -120,152 +512,1475 @@ impl mutstr {
     /// ### Example
     /// ```
     /// use mutstr::mutstr;
-    /// let result = mutstr::default();
-    /// assert_eq!(result.is_empty(), true);
+    /// let result = mutstr::default();
+    /// assert_eq!(result.is_empty(), true);
+    /// ```
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Returns `true` if the data is empty or only contains whitespace.
+    ///
+    /// **Notice:** _Stops at the first char that is not whitespace_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// assert!(mutstr::from("   ").is_blank());
+    /// assert!(mutstr::default().is_blank());
+    /// assert!(!mutstr::from(" a ").is_blank());
+    /// ```
+    #[inline]
+    pub fn is_blank(&self) -> bool {
+        self.as_str().chars().all(char::is_whitespace)
+    }
+
+    /// Get the number of whitespace separated words, like `split_whitespace().count()`.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// assert_eq!(mutstr::from("  hello   world  ").word_count(), 2);
+    /// assert_eq!(mutstr::from("   ").word_count(), 0);
+    /// ```
+    #[inline]
+    pub fn word_count(&self) -> usize {
+        self.as_str().split_whitespace().count()
+    }
+
+    /// Get the number of bytes that can be stored without reallocating.
+    ///
+    /// **Notice:** _The capacity is stored on the heap, so `mutstr` still uses 16 bytes_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("abc");
+    /// assert_eq!(result.capacity(), 3);
+    /// assert_eq!(mutstr::default().capacity(), 0);
+    /// ```
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self._ptr.capacity()
+    }
+
+    /// Get the number of allocated bytes that are not used, short version of `self.capacity() - self.size()`.
+    ///
+    /// **Notice:** _Can be used to find call sites that allocate too much_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.reserve(10);
+    /// assert!(result.wasted_capacity() > 0);
+    /// result.shrink_to_fit();
+    /// assert_eq!(result.wasted_capacity(), 0);
+    /// ```
+    #[inline(always)]
+    pub fn wasted_capacity(&self) -> usize {
+        self.capacity() - self.size()
+    }
+
+    /// Reserves capacity for at least `additional` more bytes.
+    ///
+    /// **Notice:** _Like `push()`, the capacity grows at least to the double. Use `reserve_exact()` to avoid that_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.reserve(10);
+    /// assert!(result.capacity() >= 13);
+    /// ```
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self._ptr.reserve(additional);
+    }
+
+    /// Reserves capacity for exactly `additional` more bytes, if the capacity is not large enough yet.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.reserve_exact(10);
+    /// assert_eq!(result.capacity(), 13);
+    /// ```
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self._ptr.reserve_exact(additional);
+    }
+
+    /// Reallocates the existing heap to the size, to free the unused capacity.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.push("d");
+    /// assert!(result.capacity() > 4);
+    /// result.shrink_to_fit();
+    /// assert_eq!(result.capacity(), 4);
+    /// ```
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self._ptr.set_capacity(self.size());
+    }
+
+    /// Reallocates the existing heap to `min_capacity` bytes, but never below the size.
+    ///
+    /// Does nothing if the capacity is already `min_capacity` or smaller.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.reserve(100);
+    /// result.shrink_to(10);
+    /// assert_eq!(result.capacity(), 10);
+    /// result.shrink_to(0);
+    /// assert_eq!(result.capacity(), 3);
+    /// ```
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if min_capacity < self.capacity() {
+            self._ptr.set_capacity(self.size().max(min_capacity));
+        }
+    }
+
+    /// Get the layout of the allocated bytes.
+    ///
+    /// **Notice:** _The size is the capacity, without the header in front of the bytes that stores it_.
+    ///
+    /// ### Warning
+    /// This is not the layout of the allocation anymore, because the capacity is stored in front of the bytes.
+    /// Never pass it to `std::alloc::dealloc()` together with `ptr_mut()`, that is undefined behavior.
+    /// Use `clear_and_dealloc()` to free the memory, or `into_raw_parts()` and `mutstr::from_raw_parts()`
+    /// to pass the ownership around.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("abc");
+    /// #[allow(deprecated)]
+    /// let result_layout = result.layout();
+    /// assert_eq!(result_layout.size(), 3);
+    /// ```
+    #[inline(always)]
+    #[deprecated(note = "not the layout of the allocation, use `capacity()` for the size or `clear_and_dealloc()` to free the memory")]
+    pub fn layout(&self) -> alloc::Layout {
+        self._ptr.layout()
+    }
+
+    /// Get the allocated data as `&[u8]`.
+    ///
+    /// **Notice:** _Can be used to compare with `&str`_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let first = "abc";
+    /// let second = mutstr::from("abc");
+    /// assert_eq!(first.as_bytes(), second.as_bytes());
+    /// ```
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr(), self.size()) }
+    }
+
+    /// Get the allocated data as `&mut [u8]`.
+    ///
+    /// **Notice:** _Like `as_bytes()` but mutable_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("Hello");
+    ///
+    /// unsafe {
+    ///     let mut bytes = result.as_bytes_mut();
+    ///     bytes[0] = 0x6f; // o
+    ///     bytes[1] = 0x6c; // l
+    ///     bytes[2] = 0x6c; // l
+    ///     bytes[3] = 0x65; // e
+    ///     bytes[4] = 0x48; // H
+    /// };
+    ///
+    /// assert_eq!(result.as_bytes(), b"olleH");
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
+        std::slice::from_raw_parts_mut(self.ptr_mut(), self.size())
+    }
+
+    /// Borrow the allocated data as [`AsciiMutStr`], if all bytes are ASCII.
+    ///
+    /// **Notice:** _A safe alternative to `as_bytes_mut()` for ASCII data_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("Hello");
+    /// result.try_as_ascii().unwrap().reverse();
+    /// assert_eq!(result.as_str(), "olleH");
+    ///
+    /// let mut result = mutstr::from("café");
+    /// assert!(result.try_as_ascii().is_none());
+    /// ```
+    #[inline]
+    pub fn try_as_ascii(&mut self) -> Option<AsciiMutStr<'_>> {
+        if self.as_bytes().is_ascii() {
+            Some(AsciiMutStr::new(self))
+        } else {
+            None
+        }
+    }
+
+    /// Get the allocated data as `&str`.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("abc");
+    /// assert_eq!(result.as_str(), "abc");
+    /// ```
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Borrow the allocated data through a [`StrGuard`].
+    ///
+    /// **Notice:** _The `mutstr` can't be mutated while the guard is alive, which keeps `StrGuard::as_ptr()` valid for FFI_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("abc");
+    /// let guard = result.borrow_str();
+    /// assert_eq!(guard.as_ptr(), result.ptr());
+    /// assert_eq!(guard.as_str(), "abc");
+    /// ```
+    #[inline(always)]
+    pub fn borrow_str(&self) -> StrGuard<'_> {
+        StrGuard::new(self)
+    }
+
+    /// Get a subslice of the data, without the bounds and char boundary checks of the `Index` implementations.
+    ///
+    /// ### Safety
+    /// The range must be in bounds and both ends must be char boundaries, like for `str::get_unchecked()`.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("a😀b");
+    /// unsafe {
+    ///     assert_eq!(result.get_unchecked(1..5), "😀");
+    ///     assert_eq!(result.get_unchecked(5..), "b");
+    /// };
+    /// ```
+    #[inline(always)]
+    pub unsafe fn get_unchecked<R>(&self, range: R) -> &str
+    where
+        R: std::slice::SliceIndex<str, Output = str>,
+    {
+        self.as_str().get_unchecked(range)
+    }
+
+    /// Get the allocated data as `&str`, if it is valid UTF-8.
+    ///
+    /// **Notice:** _A checked alternative to `as_str()` for data created with `mutstr::from_raw_parts()`_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let (ptr, size) = mutstr::from("abc").into_raw_parts();
+    /// unsafe {
+    ///     *ptr = 0xff;
+    /// };
+    /// let result = unsafe { mutstr::from_raw_parts(ptr, size) };
+    /// assert!(result.try_as_str().is_err());
+    /// assert_eq!(mutstr::from("abc").try_as_str(), Ok("abc"));
+    /// ```
+    #[inline]
+    pub fn try_as_str(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(self.as_bytes())
+    }
+
+    /// Get the allocated data as `&mut str`.
+    ///
+    /// **Notice:** _Like `as_str()` but mutable_
+    #[inline(always)]
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn as_str_mut(&mut self) -> &mut str {
+        std::str::from_utf8_unchecked_mut(self.as_bytes_mut())
+    }
+
+    /// Compares the data with `other` in a time that only depends on the length, not on the content of the bytes.
+    ///
+    /// **Notice:** _Different lengths return `false` right away, so only use it where the length is not secret.
+    /// The optimizer is kept from short-circuiting with `std::hint::black_box`, which is best-effort_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("secret");
+    /// assert!(result.ct_eq_bytes(b"secret"));
+    /// assert!(!result.ct_eq_bytes(b"secreT"));
+    /// assert!(!result.ct_eq_bytes(b"secrets"));
+    /// ```
+    pub fn ct_eq_bytes(&self, other: &[u8]) -> bool {
+        if self.size() != other.len() {
+            return false;
+        }
+
+        let difference = self
+            .as_bytes()
+            .iter()
+            .zip(other)
+            .fold(0u8, |difference, (a, b)| std::hint::black_box(difference | (a ^ b)));
+        difference == 0
+    }
+
+    /// Computes the 64-bit FNV-1a hash of the data.
+    ///
+    /// **Notice:** _Unlike the `DefaultHasher`, the result is stable across versions and platforms_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// assert_eq!(mutstr::from("a").fnv1a_hash(), 0xaf63dc4c8601ec8c);
+    /// assert_eq!(mutstr::from("foobar").fnv1a_hash(), 0x85944171f73967e8);
+    /// assert_eq!(mutstr::default().fnv1a_hash(), 0xcbf29ce484222325);
+    /// ```
+    pub fn fnv1a_hash(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const PRIME: u64 = 0x100000001b3;
+        self.as_bytes()
+            .iter()
+            .fold(OFFSET_BASIS, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(PRIME))
+    }
+
+    /// Reads the first 8 bytes as little-endian `u64`, padded with zeros if the data is shorter.
+    ///
+    /// **Notice:** _A cheap but coarse key for bucketing or sharding, not a replacement for a hash_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// assert_eq!(mutstr::from("abc").prefix_u64(), 0x636261);
+    /// assert_eq!(mutstr::from("abcdefghij").prefix_u64(), u64::from_le_bytes(*b"abcdefgh"));
+    /// ```
+    pub fn prefix_u64(&self) -> u64 {
+        let mut bytes = [0; 8];
+        let len = self.size().min(bytes.len());
+        bytes[..len].copy_from_slice(&self.as_bytes()[..len]);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Get the length of the bytes `self` and `other` start with.
+    ///
+    /// **Notice:** _The length is clamped to a char boundary, so it never splits a char_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("foobar");
+    /// assert_eq!(result.common_prefix_len("foobaz"), 5);
+    /// // "é" and "è" share their first byte.
+    /// assert_eq!(mutstr::from("é").common_prefix_len("è"), 0);
+    /// ```
+    pub fn common_prefix_len(&self, other: &str) -> usize {
+        let mut len = self
+            .as_bytes()
+            .iter()
+            .zip(other.as_bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !self.as_str().is_char_boundary(len) {
+            len -= 1;
+        }
+        len
+    }
+
+    /// Get the length of the bytes `self` and `other` end with.
+    ///
+    /// **Notice:** _The length is clamped to a char boundary, so it never splits a char_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("testing");
+    /// assert_eq!(result.common_suffix_len("running"), 3);
+    /// // "é" and "ʩ" share their last byte.
+    /// assert_eq!(mutstr::from("é").common_suffix_len("ʩ"), 0);
+    /// ```
+    pub fn common_suffix_len(&self, other: &str) -> usize {
+        let mut len = self
+            .as_bytes()
+            .iter()
+            .rev()
+            .zip(other.as_bytes().iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !self.as_str().is_char_boundary(self.size() - len) {
+            len -= 1;
+        }
+        len
+    }
+
+    /// Get the longest contiguous substring that also occurs in `other`, borrowed from `self`.
+    ///
+    /// If there are several of the same length, the first one in `self` is returned.
+    ///
+    /// **Notice:** _The dynamic programming over chars takes `O(n * m)` time and `O(m)` memory, for `n` chars in `self` and `m` chars in `other`_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("abcdef");
+    /// assert_eq!(result.longest_common_substring("zcdez"), "cde");
+    /// assert_eq!(result.longest_common_substring("xyz"), "");
+    /// assert_eq!(mutstr::from("a😀b😀").longest_common_substring("😀b😀c"), "😀b😀");
+    /// ```
+    pub fn longest_common_substring(&self, other: &str) -> &str {
+        let other: Vec<char> = other.chars().collect();
+        // `lengths[j + 1]` is the length in chars of the common suffix ending at the current char and `other[j]`.
+        let mut lengths = vec![0usize; other.len() + 1];
+        // `best_end` is the byte offset behind the last char of the best match.
+        let (mut best_len, mut best_end) = (0, 0);
+        for (offset, ch) in self.as_str().char_indices() {
+            for j in (0..other.len()).rev() {
+                lengths[j + 1] = if other[j] == ch { lengths[j] + 1 } else { 0 };
+                if lengths[j + 1] > best_len {
+                    best_len = lengths[j + 1];
+                    best_end = offset + ch.len_utf8();
+                }
+            }
+        }
+        if best_len == 0 {
+            return "";
+        }
+
+        let value = &self.as_str()[..best_end];
+        let start = value.char_indices().nth_back(best_len - 1).map_or(0, |(index, _)| index);
+        &value[start..]
+    }
+
+    /// Splits the data at the byte offset `mid`, rounded down to the nearest char boundary.
+    ///
+    /// **Notice:** _Never panics, an offset behind the end splits at the end_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("a😀b");
+    /// // Byte 3 is inside of "😀".
+    /// assert_eq!(result.split_at_lossy(3), ("a", "😀b"));
+    /// assert_eq!(result.split_at_lossy(100), ("a😀b", ""));
+    /// ```
+    pub fn split_at_lossy(&self, mid: usize) -> (&str, &str) {
+        let mut mid = mid.min(self.size());
+        while !self.as_str().is_char_boundary(mid) {
+            mid -= 1;
+        }
+        self.as_str().split_at(mid)
+    }
+
+    /// Splits the data in front of the first char matching `pred`.
+    ///
+    /// **Notice:** _If no char matches, the second part is empty_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("123abc");
+    /// assert_eq!(result.split_at_first_char_matching(|c| !c.is_ascii_digit()), ("123", "abc"));
+    /// assert_eq!(result.split_at_first_char_matching(char::is_whitespace), ("123abc", ""));
+    /// ```
+    #[inline]
+    pub fn split_at_first_char_matching<P>(&self, pred: P) -> (&str, &str)
+    where
+        P: Fn(char) -> bool,
+    {
+        let mid = self.as_str().find(pred).unwrap_or(self.size());
+        self.as_str().split_at(mid)
+    }
+
+    /// An iterator over the whitespace separated parts and their starting byte offsets.
+    ///
+    /// **Notice:** _Like `str::split_whitespace()`, but with the position of each part_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("  foo bar");
+    /// let mut iter = result.split_whitespace_indices();
+    /// assert_eq!(iter.next(), Some((2, "foo")));
+    /// assert_eq!(iter.next(), Some((6, "bar")));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn split_whitespace_indices(&self) -> SplitWhitespaceIndices<'_> {
+        SplitWhitespaceIndices::new(self.as_str())
+    }
+
+    /// Returns `true` if the data ends with `\n` or `\r\n`.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// assert!(mutstr::from("line\n").ends_with_newline());
+    /// assert!(mutstr::from("line\r\n").ends_with_newline());
+    /// assert!(!mutstr::from("line").ends_with_newline());
+    /// ```
+    #[inline]
+    pub fn ends_with_newline(&self) -> bool {
+        self.as_bytes().ends_with(b"\n")
+    }
+
+    /// An iterator over the extended grapheme clusters, the characters perceived by the user.
+    ///
+    /// **Notice:** _Requires the `unicode` feature_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// // "e" followed by a combining acute accent.
+    /// let result = mutstr::from("e\u{301}x");
+    /// let graphemes: Vec<&str> = result.split_graphemes().collect();
+    /// assert_eq!(graphemes, ["e\u{301}", "x"]);
+    /// ```
+    #[cfg(feature = "unicode")]
+    #[inline]
+    pub fn split_graphemes(&self) -> impl DoubleEndedIterator<Item = &str> + '_ {
+        unicode_segmentation::UnicodeSegmentation::graphemes(self.as_str(), true)
+    }
+
+    /// An iterator over chunks of at most `max_bytes` bytes, which never split a char.
+    ///
+    /// **Notice:** _A char with more than `max_bytes` bytes is returned as a chunk of its own_.
+    ///
+    /// ### Panics
+    /// Panics if `max_bytes` is `0`.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("abc😀d");
+    /// let chunks: Vec<&str> = result.byte_chunks(2).collect();
+    /// assert_eq!(chunks, ["ab", "c", "😀", "d"]);
+    ///
+    /// let result = mutstr::from("a😀b");
+    /// let chunks: Vec<&str> = result.byte_chunks(3).collect();
+    /// assert_eq!(chunks, ["a", "😀", "b"]);
+    /// ```
+    #[inline]
+    pub fn byte_chunks(&self, max_bytes: usize) -> ByteChunks<'_> {
+        assert!(max_bytes != 0, "max_bytes must not be 0");
+        ByteChunks::new(self.as_str(), max_bytes)
+    }
+
+    /// Returns `true` if the data and `other` have the same number of chars.
+    ///
+    /// **Notice:** _If both are ASCII, only the sizes are compared_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("abc");
+    /// assert!(result.same_char_count("xyz"));
+    /// assert!(!mutstr::from("ab").same_char_count("abc"));
+    /// assert!(mutstr::from("a😀").same_char_count("ab"));
+    /// ```
+    pub fn same_char_count(&self, other: &str) -> bool {
+        if self.as_bytes().is_ascii() && other.is_ascii() {
+            return self.size() == other.len();
+        }
+        self.as_str().chars().count() == other.chars().count()
+    }
+
+    /// Get the number of chars in the byte range `range`.
+    ///
+    /// ### Panics
+    /// Panics if the start of `range` is greater than its end, or if one of them is not a char boundary.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("a😀b");
+    /// assert_eq!(result.char_count_in(0..5), 2);
+    /// assert_eq!(result.char_count_in(5..6), 1);
+    /// ```
+    pub fn char_count_in(&self, range: ops::Range<usize>) -> usize {
+        let value = self.as_str();
+        assert!(
+            range.start <= range.end && value.is_char_boundary(range.start) && value.is_char_boundary(range.end),
+            "byte range {range:?} is not a valid char range of a `mutstr` with {} bytes",
+            self.size()
+        );
+        // Every char starts with exactly one byte that is not a continuation byte (`0b10xx_xxxx`).
+        self.as_bytes()[range]
+            .iter()
+            .filter(|byte| (**byte as i8) >= -0x40)
+            .count()
+    }
+
+    /// Get the char index of the first occurrence of `pat`, unlike `find()` which returns the byte offset.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("😀ab");
+    /// assert_eq!(result.find_char_index('a'), Some(1));
+    /// assert_eq!(result.find_char_index('b'), Some(2));
+    /// assert_eq!(result.as_str().find('b'), Some(5));
+    /// assert_eq!(result.find_char_index('c'), None);
+    /// ```
+    #[inline]
+    pub fn find_char_index(&self, pat: char) -> Option<usize> {
+        self.as_str().chars().position(|ch| ch == pat)
+    }
+
+    /// Get the `n`th (0-indexed) char, like `chars().nth(n)`.
+    ///
+    /// **Notice:** _Walks the chars from the start. Use `char_index()` for repeated access_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("a😀b");
+    /// assert_eq!(result.nth_char(1), Some('😀'));
+    /// assert_eq!(result.nth_char(5), None);
+    /// ```
+    #[inline]
+    pub fn nth_char(&self, n: usize) -> Option<char> {
+        self.as_str().chars().nth(n)
+    }
+
+    /// Computes the byte offsets of all chars, to map char indices to byte offsets in `O(1)`.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("a😀b");
+    /// assert_eq!(result.char_index().byte_offset(2), Some(5));
+    /// ```
+    #[inline]
+    pub fn char_index(&self) -> CharIndex<'_> {
+        CharIndex::new(self)
+    }
+
+    /// Collects the chars into a `Vec<char>`.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("a😀b");
+    /// let chars = result.to_chars();
+    /// assert_eq!(chars, ['a', '😀', 'b']);
+    /// assert_eq!(mutstr::from_chars_vec(chars).as_str(), "a😀b");
+    /// ```
+    #[inline]
+    pub fn to_chars(&self) -> Vec<char> {
+        self.as_str().chars().collect()
+    }
+
+    /// Creates a new `mutstr` by mapping every char to zero or more chars.
+    ///
+    /// **Notice:** _The new `mutstr` starts with the same capacity and grows like `push()` if the chars need more bytes_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("ab");
+    /// assert_eq!(result.map_chars(|c| [c, c]).as_str(), "aabb");
+    /// // Remove chars by returning nothing.
+    /// assert_eq!(result.map_chars(|c| (c != 'a').then_some(c)).as_str(), "b");
+    /// ```
+    pub fn map_chars<F, I>(&self, f: F) -> mutstr
+    where
+        F: FnMut(char) -> I,
+        I: IntoIterator<Item = char>,
+    {
+        let mut new_value = Self::with_capacity(self.size());
+        let mut buffer = [0; 4];
+        for ch in self.as_str().chars().flat_map(f) {
+            new_value._ptr.extend(ch.encode_utf8(&mut buffer).as_bytes());
+        }
+        new_value
+    }
+
+    /// Reverses the order of the bytes in place.
+    ///
+    /// ### Safety
+    /// The reversed bytes must be valid UTF-8. This is only the case if all bytes are ASCII
+    /// or the bytes are a palindrome, because reversing a multibyte char puts its continuation bytes first.
+    /// Use `try_as_ascii()` for a safe alternative.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// unsafe {
+    ///     result.reverse_bytes();
+    /// };
+    /// assert_eq!(result.as_str(), "cba");
+    /// ```
+    #[inline]
+    pub unsafe fn reverse_bytes(&mut self) {
+        self.as_bytes_mut().reverse();
+    }
+
+    /// Creates a new `mutstr` with all ASCII letters converted to lower case.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("ABC é");
+    /// assert_eq!(result.to_ascii_lowercase().as_str(), "abc é");
+    /// assert_eq!(result.as_str(), "ABC é");
+    /// ```
+    #[inline]
+    pub fn to_ascii_lowercase(&self) -> mutstr {
+        let mut new_value = self.clone();
+        new_value.make_ascii_lowercase();
+        new_value
+    }
+
+    /// Creates a new `mutstr` with all ASCII letters converted to upper case.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("abc");
+    /// assert_eq!(result.to_ascii_uppercase().as_str(), "ABC");
+    /// assert_eq!(result.as_str(), "abc");
+    /// ```
+    #[inline]
+    pub fn to_ascii_uppercase(&self) -> mutstr {
+        let mut new_value = self.clone();
+        new_value.make_ascii_uppercase();
+        new_value
+    }
+
+    /// Converts all ASCII letters to lower case, without reallocating.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("ABC");
+    /// result.make_ascii_lowercase();
+    /// assert_eq!(result.as_str(), "abc");
+    /// ```
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        unsafe { self.as_bytes_mut() }.make_ascii_lowercase();
+    }
+
+    /// Converts all ASCII letters to upper case, without reallocating.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.make_ascii_uppercase();
+    /// assert_eq!(result.as_str(), "ABC");
+    /// ```
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        unsafe { self.as_bytes_mut() }.make_ascii_uppercase();
+    }
+
+    /// Creates a new `mutstr` by mapping every char to exactly one char.
+    ///
+    /// **Notice:** _The new `mutstr` starts with the same capacity and only grows if chars are mapped to wider ones_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let rot13 = |c: char| match c {
+    ///     'a'..='m' | 'A'..='M' => (c as u8 + 13) as char,
+    ///     'n'..='z' | 'N'..='Z' => (c as u8 - 13) as char,
+    ///     _ => c,
+    /// };
+    /// let result = mutstr::from("Hello");
+    /// assert_eq!(result.map_chars_to_string(rot13).as_str(), "Uryyb");
+    /// ```
+    pub fn map_chars_to_string<F>(&self, mut f: F) -> mutstr
+    where
+        F: FnMut(char) -> char,
+    {
+        let mut new_value = Self::with_capacity(self.size());
+        let mut buffer = [0; 4];
+        for ch in self.as_str().chars() {
+            new_value._ptr.extend(f(ch).encode_utf8(&mut buffer).as_bytes());
+        }
+        new_value
+    }
+
+    /// Creates a new `mutstr` by repeating the data `n` times.
+    ///
+    /// **Notice:** _Also available as `*` operator_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("ab");
+    /// assert_eq!(result.repeat(3).as_str(), "ababab");
+    /// assert_eq!(result.repeat(0).capacity(), 0);
+    /// assert_eq!(mutstr::default().repeat(usize::MAX).as_str(), "");
+    /// ```
+    pub fn repeat(&self, n: usize) -> mutstr {
+        if self.is_empty() || n == 0 {
+            return Self::default();
+        }
+
+        let new_size = self.size().checked_mul(n).expect("capacity overflow");
+        let mut new_value = Self::with_capacity(new_size);
+        new_value._ptr.extend(self.as_bytes());
+        // Doubles the written bytes by copying them behind themselves, like `str::repeat()`.
+        unsafe {
+            let base = new_value._ptr.raw();
+            let mut written = self.size();
+            while written <= new_size - written {
+                std::ptr::copy_nonoverlapping(base, base.add(written), written);
+                written *= 2;
+            }
+            std::ptr::copy_nonoverlapping(base, base.add(written), new_size - written);
+            new_value._ptr.set_size(new_size);
+        };
+        new_value
+    }
+
+    /// Creates a new `mutstr` of at most `target_bytes` bytes by repeating the data.
+    ///
+    /// **Notice:** _The last copy is cut off at a char boundary, so a char that doesn't fit is dropped_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let result = mutstr::from("ab");
+    /// assert_eq!(result.cycle_to_len(5).as_str(), "ababa");
+    /// // "😀" uses 4 bytes.
+    /// assert_eq!(mutstr::from("😀").cycle_to_len(6).as_str(), "😀");
+    /// ```
+    pub fn cycle_to_len(&self, target_bytes: usize) -> mutstr {
+        if self.is_empty() {
+            return Self::default();
+        }
+
+        let mut remaining = target_bytes % self.size();
+        while !self.as_str().is_char_boundary(remaining) {
+            remaining -= 1;
+        }
+        let copies = target_bytes / self.size();
+        let mut new_value = Self::with_capacity(copies * self.size() + remaining);
+        for _ in 0..copies {
+            new_value._ptr.extend(self.as_bytes());
+        }
+        new_value._ptr.extend(&self.as_bytes()[..remaining]);
+        new_value
+    }
+
+    /// Overwrites the bytes with a copy of `value`.
+    ///
+    /// **Notice:** _Reallocates the existing heap only if `value` is larger than the capacity, a smaller `value` reuses it_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::default();
+    /// // &str
+    /// result.replace_with("123");
+    /// assert_eq!(result.as_str(), "123");
+    /// // &[u8; 3]
+    /// result.replace_with(b"abc");
+    /// assert_eq!(result.as_str(), "abc");
+    /// // Shorter values keep the capacity.
+    /// result.replace_with("x");
+    /// assert_eq!(result.as_str(), "x");
+    /// assert_eq!(result.capacity(), 3);
+    /// ```
+    pub fn replace_with<T>(&mut self, value: T)
+    where 
+        T: AsRef<[u8]>,
+    {
+        let value_ref = value.as_ref();
+        let value_size = std::mem::size_of_val(value_ref);
+        if value_size > self.capacity() {
+            self._ptr.set_capacity(value_size);
+        }
+        unsafe {
+            std::ptr::copy(value_ref.as_ptr(), self.ptr_mut(), value_size);
+            self._ptr.set_size(value_size);
+        };
+    }
+
+    /// Overwrites the bytes with a copy of `value` like `replace_with()` and returns `self` for chaining.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.assign("x").push("y");
+    /// assert_eq!(result.as_str(), "xy");
+    /// assert_eq!(result.capacity(), 3);
+    /// ```
+    #[inline]
+    pub fn assign(&mut self, value: &str) -> &mut Self {
+        self.replace_with(value);
+        self
+    }
+
+    /// Overwrites the bytes with the formatted `args`, reusing the capacity.
+    ///
+    /// **Notice:** _Use the [`replace_with_fmt!`] macro to create `args`. Unlike `format!()`, no `String` is allocated_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc123");
+    /// result.replace_with_fmt(format_args!("x={}", 42));
+    /// assert_eq!(result.as_str(), "x=42");
+    /// assert_eq!(result.capacity(), 6);
+    /// ```
+    pub fn replace_with_fmt(&mut self, args: fmt::Arguments<'_>) {
+        self.clear();
+        fmt::Write::write_fmt(self, args).expect("a formatting trait implementation returned an error");
+    }
+
+    /// Writes `value` at the end and reallocates the existing heap if the capacity is exhausted.
+    ///
+    /// **Notice:** _The capacity grows at least to the double, so a series of pushes only reallocates `O(log n)` times_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// // &str
+    /// result.push("123");
+    /// assert_eq!(result.as_str(), "abc123");
+    /// // &[u8; 3]
+    /// result.push(b"456");
+    /// assert_eq!(result.as_str(), "abc123456");
+    /// ```
+    pub fn push<T>(&mut self, value: T) 
+    where 
+        T: AsRef<[u8]>
+    {
+        let value_ref = value.as_ref();
+        if value_ref.is_empty() {
+            return;
+        }
+
+        self._ptr.extend(value_ref);
+    }
+
+    /// Writes `value` at the end, escaped to be used inside a JSON string.
+    ///
+    /// **Notice:** _Escapes `"`, `\` and control chars. The surrounding quotes are not written_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("{\"name\":\"");
+    /// result.push_json_escaped("a\"b\nc\u{1}");
+    /// result.push("\"}");
+    /// assert_eq!(result.as_str(), r#"{"name":"a\"b\nc\u0001"}"#);
+    /// ```
+    pub fn push_json_escaped(&mut self, value: &str) {
+        const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        self.reserve(value.len());
+        let bytes = value.as_bytes();
+        let mut start = 0;
+        for (index, byte) in bytes.iter().enumerate() {
+            let unicode_escape;
+            let escaped: &[u8] = match byte {
+                b'"' => b"\\\"",
+                b'\\' => b"\\\\",
+                b'\n' => b"\\n",
+                b'\r' => b"\\r",
+                b'\t' => b"\\t",
+                0x08 => b"\\b",
+                0x0c => b"\\f",
+                0x00..=0x1f => {
+                    let (high, low) = (usize::from(byte >> 4), usize::from(byte & 0xf));
+                    unicode_escape = [b'\\', b'u', b'0', b'0', HEX_DIGITS[high], HEX_DIGITS[low]];
+                    &unicode_escape
+                }
+                _ => continue,
+            };
+            self._ptr.extend(&bytes[start..index]);
+            self._ptr.extend(escaped);
+            start = index + 1;
+        }
+        self._ptr.extend(&bytes[start..]);
+    }
+
+    /// Writes `n` copies of `ch` at the end, reserving the capacity for all of them at once.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("x");
+    /// result.push_char_repeated(' ', 4);
+    /// assert_eq!(result.as_str(), "x    ");
+    /// result.push_char_repeated('😀', 2);
+    /// assert_eq!(result.as_str(), "x    😀😀");
+    /// ```
+    pub fn push_char_repeated(&mut self, ch: char, n: usize) {
+        let mut buffer = [0; 4];
+        let encoded = ch.encode_utf8(&mut buffer).as_bytes();
+        self.reserve(encoded.len().checked_mul(n).expect("capacity overflow"));
+        for _ in 0..n {
+            self._ptr.extend(encoded);
+        }
+    }
+
+    /// Appends the hex encoding of `bytes`, two ASCII chars per byte.
+    ///
+    /// **Notice:** _The capacity is reserved once for the complete encoding_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("0x");
+    /// result.push_hex(&[0xAB, 0xCD], false);
+    /// assert_eq!(result.as_str(), "0xabcd");
+    /// result.push_hex(&[0x0F], true);
+    /// assert_eq!(result.as_str(), "0xabcd0F");
+    /// ```
+    pub fn push_hex(&mut self, bytes: &[u8], uppercase: bool) {
+        let digits = if uppercase {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+        self.reserve(bytes.len().checked_mul(2).expect("capacity overflow"));
+        for byte in bytes {
+            self._ptr.extend(&[digits[(byte >> 4) as usize], digits[(byte & 0x0f) as usize]]);
+        }
+    }
+
+    /// Reserves the exact sum of the sizes of `values` and writes all of them at the end.
+    ///
+    /// **Notice:** _Reallocates the existing heap at most once_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.push_all_exact(&["1", "23", "456"]);
+    /// assert_eq!(result.as_str(), "abc123456");
+    /// assert_eq!(result.capacity(), 9);
+    /// ```
+    pub fn push_all_exact(&mut self, values: &[&str]) {
+        let values_size = values
+            .iter()
+            .try_fold(0usize, |size, value| size.checked_add(value.len()))
+            .expect("capacity overflow");
+        self.reserve_exact(values_size);
+        for value in values {
+            self._ptr.extend(value.as_bytes());
+        }
+    }
+
+    /// Writes `item` at the end, preceded by `sep` if `self` is not empty.
+    ///
+    /// **Notice:** _Can be used to build delimited lists without a trailing separator_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::default();
+    /// for item in ["a", "b", "c"] {
+    ///     result.push_with_sep(item, ", ");
+    /// }
+    /// assert_eq!(result.as_str(), "a, b, c");
+    /// ```
+    pub fn push_with_sep(&mut self, item: &str, sep: &str) {
+        if self.is_empty() {
+            self.push(item);
+            return;
+        }
+
+        self.reserve(sep.len() + item.len());
+        self._ptr.extend(sep.as_bytes());
+        self._ptr.extend(item.as_bytes());
+    }
+
+    /// Appends every line followed by `'\n'`.
+    ///
+    /// **Notice:** _One byte per line is reserved upfront, based on the lower bound of the `size_hint()`.
+    /// The lines themselves grow the capacity like `push()`_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::default();
+    /// result.push_lines(["a", "b", "c"]);
+    /// assert_eq!(result.as_str(), "a\nb\nc\n");
+    /// ```
+    pub fn push_lines<I, S>(&mut self, lines: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let lines = lines.into_iter();
+        self.reserve(lines.size_hint().0);
+        for line in lines {
+            self._ptr.extend(line.as_ref().as_bytes());
+            self._ptr.extend(b"\n");
+        }
+    }
+
+    /// Writes `suffix` at the end, if the data doesn't end with it already.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("dir");
+    /// result.ensure_suffix("/");
+    /// assert_eq!(result.as_str(), "dir/");
+    /// result.ensure_suffix("/");
+    /// assert_eq!(result.as_str(), "dir/");
+    /// ```
+    #[inline]
+    pub fn ensure_suffix(&mut self, suffix: &str) {
+        if !self.as_str().ends_with(suffix) {
+            self.push(suffix);
+        }
+    }
+
+    /// Writes `prefix` at the start, if the data doesn't start with it already.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("example.com");
+    /// result.ensure_prefix("https://");
+    /// assert_eq!(result.as_str(), "https://example.com");
+    /// result.ensure_prefix("https://");
+    /// assert_eq!(result.as_str(), "https://example.com");
+    /// ```
+    #[inline]
+    pub fn ensure_prefix(&mut self, prefix: &str) {
+        if !self.as_str().starts_with(prefix) {
+            self._ptr.splice(0..0, prefix.as_bytes());
+        }
+    }
+
+    /// Removes `prefix` from the start, moves the remaining bytes to the front and reallocates the existing heap
+    /// to the new size, if the data starts with it.
+    ///
+    /// Returns `true` if `prefix` was removed, an empty `prefix` changes nothing and returns `false`.
+    ///
+    /// **Notice:** _Unlike `remove_suffix()`, the capacity is freed_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("www.example.com");
+    /// assert!(result.remove_prefix("www."));
+    /// assert_eq!(result.as_str(), "example.com");
+    /// assert_eq!(result.capacity(), 11);
+    /// assert!(!result.remove_prefix("www."));
+    /// // An empty prefix changes nothing and keeps the capacity.
+    /// assert!(!result.remove_prefix(""));
+    /// assert_eq!(result.capacity(), 11);
+    /// ```
+    pub fn remove_prefix(&mut self, prefix: &str) -> bool {
+        if prefix.is_empty() || !self.as_str().starts_with(prefix) {
+            return false;
+        }
+
+        self._ptr.splice(0..prefix.len(), &[]);
+        self.shrink_to_fit();
+        true
+    }
+
+    /// Removes `suffix` from the end, if the data ends with it.
+    ///
+    /// Returns `true` if `suffix` was removed, an empty `suffix` changes nothing and returns `false`.
+    ///
+    /// **Notice:** _The capacity is kept, use `shrink_to_fit()` to free it_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("example.com");
+    /// assert!(result.remove_suffix(".com"));
+    /// assert_eq!(result.as_str(), "example");
+    /// assert!(!result.remove_suffix(".com"));
+    /// assert!(!result.remove_suffix(""));
+    /// assert_eq!(result.as_str(), "example");
+    /// ```
+    pub fn remove_suffix(&mut self, suffix: &str) -> bool {
+        if suffix.is_empty() || !self.as_str().ends_with(suffix) {
+            return false;
+        }
+
+        unsafe {
+            self._ptr.set_size(self.size() - suffix.len());
+        };
+        true
+    }
+
+    /// Truncates the data at the first occurrence of `ch`, removing `ch` and everything after it.
+    ///
+    /// Does nothing if `ch` doesn't occur.
+    ///
+    /// **Notice:** _The capacity is kept, use `shrink_to_fit()` to free it_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("key=value");
+    /// result.truncate_at('=');
+    /// assert_eq!(result.as_str(), "key");
+    /// ```
+    pub fn truncate_at(&mut self, ch: char) {
+        if let Some(index) = self.as_str().find(ch) {
+            unsafe {
+                self._ptr.set_size(index);
+            };
+        }
+    }
+
+    /// Truncates the data after the first occurrence of `ch`, keeping `ch` itself.
+    ///
+    /// Does nothing if `ch` doesn't occur.
+    ///
+    /// **Notice:** _The capacity is kept, use `shrink_to_fit()` to free it_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("key=value");
+    /// result.truncate_at_keep('=');
+    /// assert_eq!(result.as_str(), "key=");
     /// ```
-    #[inline(always)]
-    pub fn is_empty(&self) -> bool {
-        self.size() == 0
+    pub fn truncate_at_keep(&mut self, ch: char) {
+        if let Some(index) = self.as_str().find(ch) {
+            unsafe {
+                self._ptr.set_size(index + ch.len_utf8());
+            };
+        }
     }
 
-    /// Get the pointer layout.
+    /// Appends copies of `pattern` until the size is exactly `target_bytes`.
+    ///
+    /// The last copy of `pattern` is cut off if necessary, but only if the cut lands on a char boundary.
+    /// Otherwise [`PadError::SplitsChar`] is returned, or [`PadError::EmptyPattern`] if `pattern` is empty
+    /// and there are bytes to fill. On error, `self` is left unchanged.
+    /// Does nothing if the size is already `target_bytes` or larger.
     ///
     /// ### Example
     /// ```
     /// use mutstr::mutstr;
-    /// let result = mutstr::from("abc");
-    /// let result_layout = result.layout();
-    /// assert_eq!(result_layout.size(), 3);
+    /// let mut result = mutstr::from("x");
+    /// assert!(result.pad_with_pattern("ab", 5).is_ok());
+    /// assert_eq!(result.as_str(), "xabab");
+    /// // "é" uses 2 bytes and would be split.
+    /// assert!(result.pad_with_pattern("é", 6).is_err());
+    /// assert_eq!(result.as_str(), "xabab");
     /// ```
-    #[inline(always)]
-    pub fn layout(&self) -> alloc::Layout {
-        self._ptr.layout()
+    pub fn pad_with_pattern(&mut self, pattern: &str, target_bytes: usize) -> Result<(), PadError> {
+        let old_size = self.size();
+        if old_size >= target_bytes {
+            return Ok(());
+        }
+
+        let missing = target_bytes - old_size;
+        if pattern.is_empty() {
+            return Err(PadError::EmptyPattern { remaining: missing });
+        }
+        let remaining = missing % pattern.len();
+        if !pattern.is_char_boundary(remaining) {
+            return Err(PadError::SplitsChar { remaining });
+        }
+
+        self.reserve_exact(missing);
+        for _ in 0..missing / pattern.len() {
+            self._ptr.extend(pattern.as_bytes());
+        }
+        self._ptr.extend(&pattern.as_bytes()[..remaining]);
+        Ok(())
     }
 
-    /// Get the allocated data as `&[u8]`.
+    /// Replaces every char in the char range `range` with `mask`.
     ///
-    /// **Notice:** _Can be used to compare with `&str`_.
+    /// **Notice:** _The range counts chars, not bytes, so the number of chars stays the same_.
+    ///
+    /// ### Panics
+    /// Panics if the start of `range` is greater than its end, or if the end is greater than the number of chars.
     ///
     /// ### Example
     /// ```
     /// use mutstr::mutstr;
-    /// let first = "abc";
-    /// let second = mutstr::from("abc");
-    /// assert_eq!(first.as_bytes(), second.as_bytes());
+    /// let mut result = mutstr::from("a😀bc");
+    /// result.mask_range(1..3, '*');
+    /// assert_eq!(result.as_str(), "a**c");
     /// ```
-    #[inline(always)]
-    pub fn as_bytes(&self) -> &[u8] {
-        unsafe { std::slice::from_raw_parts(self.ptr(), self.size()) }
+    pub fn mask_range(&mut self, range: ops::Range<usize>, mask: char) {
+        assert!(range.start <= range.end, "char range starts at {} but ends at {}", range.start, range.end);
+        let mut offsets = self
+            .as_str()
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(self.size()));
+        let start = offsets.nth(range.start);
+        let end = match range.len() {
+            0 => start,
+            len => offsets.nth(len - 1),
+        };
+        let (Some(start), Some(end)) = (start, end) else {
+            panic!("char range ends at {} but the number of chars is smaller", range.end);
+        };
+
+        let mut new_value = String::with_capacity(start + range.len() * mask.len_utf8() + self.size() - end);
+        new_value.push_str(&self[..start]);
+        new_value.extend(std::iter::repeat_n(mask, range.len()));
+        new_value.push_str(&self[end..]);
+        self.replace_with(&new_value);
     }
 
-    /// Get the allocated data as `&mut [u8]`.
+    /// Replaces only the `n`th (0-indexed) occurrence of `pat` with `to`, in place.
     ///
-    /// **Notice:** _Like `as_bytes()` but mutable_.
+    /// Returns `true` if `pat` occurs more than `n` times and was replaced.
+    /// Occurrences are counted without overlap, like for `str::match_indices()`.
     ///
     /// ### Example
     /// ```
     /// use mutstr::mutstr;
-    /// let mut result = mutstr::from("Hello");
-    ///
-    /// unsafe {
-    ///     let mut bytes = result.as_bytes_mut();
-    ///     bytes[0] = 0x6f; // o
-    ///     bytes[1] = 0x6c; // l
-    ///     bytes[2] = 0x6c; // l
-    ///     bytes[3] = 0x65; // e
-    ///     bytes[4] = 0x48; // H
-    /// };
-    ///
-    /// assert_eq!(result.as_bytes(), b"olleH");
+    /// let mut result = mutstr::from("banana");
+    /// assert!(result.replace_nth("a", "X", 1));
+    /// assert_eq!(result.as_str(), "banXna");
+    /// assert!(!result.replace_nth("a", "X", 2));
     /// ```
-    #[inline(always)]
-    #[allow(clippy::missing_safety_doc)]
-    pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
-        std::slice::from_raw_parts_mut(self.ptr_mut(), self.size())
+    pub fn replace_nth(&mut self, pat: &str, to: &str, n: usize) -> bool {
+        let Some((start, _)) = self.as_str().match_indices(pat).nth(n) else {
+            return false;
+        };
+        self._ptr.splice(start..start + pat.len(), to.as_bytes());
+        true
     }
 
-    /// Get the allocated data as `&str`.
+    /// Removes a single trailing `\n` or `\r\n`.
+    ///
+    /// **Notice:** _The capacity is kept, use `shrink_to_fit()` to free it_.
     ///
     /// ### Example
     /// ```
     /// use mutstr::mutstr;
-    /// let result = mutstr::from("abc");
-    /// assert_eq!(result.as_str(), "abc");
+    /// let mut result = mutstr::from("line\n");
+    /// result.trim_newline();
+    /// assert_eq!(result.as_str(), "line");
+    /// assert_eq!(result.capacity(), 5);
+    ///
+    /// let mut result = mutstr::from("line\r\n\r\n");
+    /// result.trim_newline();
+    /// assert_eq!(result.as_str(), "line\r\n");
     /// ```
-    #[inline(always)]
-    pub fn as_str(&self) -> &str {
-        unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+    pub fn trim_newline(&mut self) {
+        let new_size = if self.as_bytes().ends_with(b"\r\n") {
+            self.size() - 2
+        } else if self.as_bytes().ends_with(b"\n") {
+            self.size() - 1
+        } else {
+            return;
+        };
+        unsafe {
+            self._ptr.set_size(new_size);
+        };
     }
 
-    /// Get the allocated data as `&mut str`.
+    /// Removes all chars for which `f` returns `false`, in place and in order.
     ///
-    /// **Notice:** _Like `as_str()` but mutable_
-    #[inline(always)]
-    #[allow(clippy::missing_safety_doc)]
-    pub unsafe fn as_str_mut(&mut self) -> &mut str {
-        std::str::from_utf8_unchecked_mut(self.as_bytes_mut())
-    }
-
-    /// Reallocates the existing heap if the size is not the same and overwrites the bytes with a copy of `value`.
+    /// **Notice:** _The capacity is kept, like for `String::retain()`. Use `retain_and_shrink()` to free it_.
     ///
     /// ### Example
     /// ```
     /// use mutstr::mutstr;
-    /// let mut result = mutstr::default();
-    /// // &str
-    /// result.replace_with("123");
-    /// assert_eq!(result.as_str(), "123");
-    /// // &[u8; 3]
-    /// result.replace_with(b"abc");
-    /// assert_eq!(result.as_str(), "abc");
+    /// let mut result = mutstr::from("a1😀b2");
+    /// result.retain(|c| !c.is_ascii_digit());
+    /// assert_eq!(result.as_str(), "a😀b");
+    /// assert_eq!(result.capacity(), 8);
     /// ```
-    pub fn replace_with<T>(&mut self, value: T)
-    where 
-        T: AsRef<[u8]>,
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(char) -> bool,
     {
-        let value_ref = value.as_ref();
-        let value_size = std::mem::size_of_val(value_ref);
-        if self.size() != value_size {
-            self._ptr.realloc(value_size);
+        // Moves the chars that were not visited yet to the front, if `f` panics.
+        struct Guard<'a> {
+            ptr: &'a mut MutStrPtr,
+            size: usize,
+            read: usize,
+            write: usize,
         }
-        unsafe {
-            std::ptr::copy(value_ref.as_ptr(), self.ptr_mut(), value_size);
+
+        impl Drop for Guard<'_> {
+            fn drop(&mut self) {
+                unsafe {
+                    let base = self.ptr.raw();
+                    std::ptr::copy(base.add(self.read), base.add(self.write), self.size - self.read);
+                    self.ptr.set_size(self.write + self.size - self.read);
+                };
+            }
+        }
+
+        let size = self.size();
+        let mut guard = Guard {
+            ptr: &mut self._ptr,
+            size,
+            read: 0,
+            write: 0,
         };
+        while guard.read < size {
+            unsafe {
+                let base = guard.ptr.raw();
+                let rest = std::slice::from_raw_parts(base.add(guard.read), size - guard.read);
+                let Some(ch) = std::str::from_utf8_unchecked(rest).chars().next() else {
+                    break;
+                };
+                let ch_size = ch.len_utf8();
+                if f(ch) {
+                    std::ptr::copy(base.add(guard.read), base.add(guard.write), ch_size);
+                    guard.write += ch_size;
+                }
+                guard.read += ch_size;
+            };
+        }
     }
 
-    /// Reallocates the existing heap and writes `value` at the end.
+    /// Like `retain()`, but reallocates the existing heap to the new size afterwards.
     ///
     /// ### Example
     /// ```
     /// use mutstr::mutstr;
-    /// let mut result = mutstr::from("abc");
-    /// // &str
-    /// result.push("123");
-    /// assert_eq!(result.as_str(), "abc123");
-    /// // &[u8; 3]
-    /// result.push(b"456");
-    /// assert_eq!(result.as_str(), "abc123456");
+    /// let mut result = mutstr::from("a1b2");
+    /// result.retain_and_shrink(|c| c.is_alphabetic());
+    /// assert_eq!(result.as_str(), "ab");
+    /// assert_eq!(result.capacity(), 2);
     /// ```
-    pub fn push<T>(&mut self, value: T) 
-    where 
-        T: AsRef<[u8]>
+    #[inline]
+    pub fn retain_and_shrink<F>(&mut self, f: F)
+    where
+        F: FnMut(char) -> bool,
     {
-        let value_ref = value.as_ref();
-        if value_ref.is_empty() {
-            return;
-        }
-
-        let value_size = std::mem::size_of_val(value_ref);
-        let old_size = self.size();
-        self._ptr.realloc(old_size + value_size);
-
-        unsafe {
-            let dst_ptr = self.ptr_mut().add(old_size);
-            std::ptr::copy(value_ref.as_ptr(), dst_ptr, value_size);
-        };
+        self.retain(f);
+        self.shrink_to_fit();
     }
 
-    /// Reallocates the existing heap to `0`, to free memory.
+    /// Sets the size to `0`, but keeps the capacity to reuse it.
+    ///
+    /// **Notice:** _Use `clear_and_dealloc()` to free memory_.
     ///
     /// ### Example
     /// ```
@@ -274,24 +1989,45 @@ impl mutstr {
     /// assert_eq!(result.size(), 3);
     /// result.clear();
     /// assert_eq!(result.size(), 0);
+    /// assert_eq!(result.capacity(), 3);
     /// ```
+    #[inline]
     pub fn clear(&mut self) {
-        self._ptr.realloc(0);
+        unsafe {
+            self._ptr.set_size(0);
+        };
+    }
+
+    /// Deallocates the existing heap, to free memory.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// let mut result = mutstr::from("abc");
+    /// result.clear_and_dealloc();
+    /// assert_eq!(result.size(), 0);
+    /// assert_eq!(result.capacity(), 0);
+    /// ```
+    #[inline]
+    pub fn clear_and_dealloc(&mut self) {
+        self._ptr.free();
     }
 }
 
 impl From<&[u8]> for mutstr {
     fn from(value: &[u8]) -> Self {
-        let value_size = std::mem::size_of_val(value);
-        unsafe {
-            let value_layout: alloc::Layout =
-                alloc::Layout::from_size_align_unchecked(value_size, 1);
-            let new_ptr: *mut u8 = alloc::alloc(value_layout);
-            std::ptr::copy(value.as_ptr(), new_ptr, value_size);
-            Self {
-                _ptr: MutStrPtr(new_ptr, value_size),
-            }
-        }
+        let mut new_ptr = MutStrPtr::with_capacity(std::mem::size_of_val(value));
+        new_ptr.extend(value);
+        Self { _ptr: new_ptr }
+    }
+}
+
+impl TryFrom<Vec<u8>> for mutstr {
+    type Error = FromUtf8Error;
+
+    #[inline]
+    fn try_from(value: Vec<u8>) -> Result<Self, FromUtf8Error> {
+        Self::from_utf8(value)
     }
 }
 
@@ -302,6 +2038,13 @@ impl From<&str> for mutstr {
     }
 }
 
+impl Clone for mutstr {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from(self.as_bytes())
+    }
+}
+
 impl Default for mutstr {
     #[inline]
     fn default() -> Self {
@@ -359,6 +2102,24 @@ impl ops::Index<ops::RangeFrom<usize>> for mutstr {
     }
 }
 
+impl ops::Mul<usize> for mutstr {
+    type Output = mutstr;
+
+    #[inline]
+    fn mul(self, rhs: usize) -> mutstr {
+        self.repeat(rhs)
+    }
+}
+
+impl ops::Mul<usize> for &mutstr {
+    type Output = mutstr;
+
+    #[inline]
+    fn mul(self, rhs: usize) -> mutstr {
+        self.repeat(rhs)
+    }
+}
+
 impl ops::AddAssign<&str> for mutstr {
     #[inline]
     fn add_assign(&mut self, rhs: &str) {
@@ -382,6 +2143,14 @@ impl ops::SubAssign<(usize, &str)> for mutstr {
     }
 }
 
+impl fmt::Write for mutstr {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push(s);
+        Ok(())
+    }
+}
+
 impl AsRef<[u8]> for mutstr {
     #[inline]
     fn as_ref(&self) -> &[u8] {
@@ -410,6 +2179,25 @@ impl AsMut<str> for mutstr {
     }
 }
 
+/// Overwrites the bytes of a [`mutstr`] with formatted text, reusing its capacity.
+///
+/// **Notice:** _Short version of `mutstr::replace_with_fmt(format_args!(...))`_.
+///
+/// ### Example
+/// ```
+/// use ::mutstr::{mutstr, replace_with_fmt};
+/// let mut result = mutstr::with_capacity(8);
+/// replace_with_fmt!(result, "x={}", 42);
+/// assert_eq!(result.as_str(), "x=42");
+/// assert_eq!(result.capacity(), 8);
+/// ```
+#[macro_export]
+macro_rules! replace_with_fmt {
+    ($dst:expr, $($arg:tt)*) => {
+        $dst.replace_with_fmt(::std::format_args!($($arg)*))
+    };
+}
+
 #[cfg(feature = "serde")]
 include!("serde.rs");
 
@@ -429,6 +2217,34 @@ mod implementations {
         assert_eq!(result.as_str(), "abc123");
     }
 
+    #[test]
+    fn clone() {
+        let first = mutstr::from("abc123");
+        let second = first.clone();
+        assert_eq!(second.as_str(), "abc123");
+        assert_ne!(first.ptr(), second.ptr());
+    }
+
+    #[test]
+    fn into_ffi() {
+        for value in ["abc123", ""] {
+            let (ptr, size, free) = mutstr::from(value).into_ffi();
+            assert_eq!(size, value.len());
+            unsafe {
+                free(ptr, size);
+            };
+        }
+    }
+
+    #[test]
+    fn try_from_vec() {
+        let result = mutstr::try_from(vec![0x61, 0x62]).unwrap();
+        assert_eq!(result.as_str(), "ab");
+
+        let result = mutstr::try_from(vec![0x61, 0xff]);
+        assert_eq!(result.unwrap_err().as_bytes(), [0x61, 0xff]);
+    }
+
     #[test]
     fn default() {
         let result = mutstr::default();
@@ -467,6 +2283,28 @@ mod implementations {
         assert_eq!(&result[0..], "abc123");
     }
 
+    #[test]
+    #[allow(clippy::erasing_op)]
+    fn mul() {
+        let result = mutstr::from("ab") * 3;
+        assert_eq!(result.as_str(), "ababab");
+
+        let result = mutstr::from("ab") * 0;
+        assert_eq!(result.as_str(), "");
+        assert_eq!(result.capacity(), 0);
+
+        let result = mutstr::default() * usize::MAX;
+        assert_eq!(result.as_str(), "");
+    }
+
+    #[test]
+    fn mul_ref() {
+        let first = mutstr::from("ab");
+        let second = &first * 2;
+        assert_eq!(first.as_str(), "ab");
+        assert_eq!(second.as_str(), "abab");
+    }
+
     #[test]
     fn add_assign() {
         let mut result = mutstr::from("Hello my");
@@ -488,6 +2326,15 @@ mod implementations {
         assert_eq!(result.as_str(), "Hello my");
     }
 
+    #[test]
+    fn write() {
+        use std::fmt::Write;
+
+        let mut result = mutstr::from("abc");
+        write!(result, "{}{}", 1, 2).unwrap();
+        assert_eq!(result.as_str(), "abc12");
+    }
+
     #[test]
     fn as_ref_u8() {
         let result = mutstr::default();
@@ -516,3 +2363,205 @@ mod implementations {
         assert_eq!(value, "");
     }
 }
+
+#[cfg(test)]
+mod capacity {
+    use super::{allocations, mutstr};
+
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<mutstr>(), 2 * std::mem::size_of::<usize>());
+    }
+
+    #[test]
+    fn push_amortized() {
+        let mut result = mutstr::default();
+        let before = allocations();
+        for _ in 0..10_000 {
+            result.push("ab");
+        }
+        assert_eq!(result.size(), 20_000);
+        assert!(allocations() - before <= 20_000usize.ilog2() as usize);
+    }
+
+    #[test]
+    fn push_all_exact() {
+        let mut result = mutstr::from("abc");
+        let before = allocations();
+        result.push_all_exact(&["1", "", "23"]);
+        assert_eq!(allocations() - before, 1);
+        assert_eq!(result.as_str(), "abc123");
+        assert_eq!(result.capacity(), 6);
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut result = mutstr::with_capacity(6);
+        let before = allocations();
+        result.push("abc");
+        result.push("123");
+        assert_eq!(allocations(), before);
+        assert_eq!(result.as_str(), "abc123");
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut result = mutstr::from("abc");
+        result.reserve(100);
+        result.shrink_to_fit();
+        assert_eq!(result.capacity(), 3);
+        assert_eq!(result.as_str(), "abc");
+
+        result.clear();
+        result.shrink_to_fit();
+        assert_eq!(result.capacity(), 0);
+    }
+
+    #[test]
+    fn map_chars() {
+        let value = mutstr::from("a😀b");
+        let before = allocations();
+        let result = value.map_chars(|c| c.to_uppercase());
+        assert_eq!(allocations() - before, 1);
+        assert_eq!(result.as_str(), "A😀B");
+    }
+
+    #[test]
+    fn repeat() {
+        let value = mutstr::from("a😀");
+        for n in 1..20 {
+            let before = allocations();
+            let result = value.repeat(n);
+            assert_eq!(allocations() - before, 1);
+            assert_eq!(result.as_str(), "a😀".repeat(n));
+            assert_eq!(result.capacity(), 5 * n);
+        }
+    }
+
+    #[test]
+    fn from_utf8() {
+        let bytes = vec![0x61, 0xf0, 0x9f, 0x98, 0x80];
+        let before = allocations();
+        let result = mutstr::from_utf8(bytes).unwrap();
+        assert_eq!(allocations() - before, 1);
+        assert_eq!(result.as_str(), "a😀");
+        assert_eq!(result.capacity(), 5);
+
+        let before = allocations();
+        let error = mutstr::from_utf8(vec![0x61, 0xff]).unwrap_err();
+        assert_eq!(allocations(), before);
+        assert_eq!(error.into_bytes(), [0x61, 0xff]);
+    }
+
+    #[test]
+    fn remove_empty_affix() {
+        let mut result = mutstr::with_capacity(16);
+        result.push("abc");
+        let before = allocations();
+        assert!(!result.remove_prefix(""));
+        assert!(!result.remove_suffix(""));
+        assert_eq!(allocations(), before);
+        assert_eq!(result.as_str(), "abc");
+        assert_eq!(result.capacity(), 16);
+    }
+
+    #[test]
+    fn shrink_to() {
+        let mut result = mutstr::from("0123456789");
+        result.reserve_exact(90);
+        assert_eq!(result.capacity(), 100);
+
+        result.shrink_to(50);
+        assert_eq!(result.capacity(), 50);
+        result.shrink_to(5);
+        assert_eq!(result.capacity(), 10);
+        assert_eq!(result.as_str(), "0123456789");
+
+        let before = allocations();
+        result.shrink_to(20);
+        assert_eq!(allocations(), before);
+        assert_eq!(result.capacity(), 10);
+    }
+
+    #[test]
+    fn clear() {
+        let mut result = mutstr::from("abc");
+        let ptr = result.ptr();
+        result.clear();
+        assert_eq!(result.as_str(), "");
+        assert_eq!(result.capacity(), 3);
+
+        let before = allocations();
+        result.push("123");
+        assert_eq!(allocations(), before);
+        assert_eq!(result.ptr(), ptr);
+        assert_eq!(result.as_str(), "123");
+    }
+
+    #[test]
+    fn clear_and_dealloc() {
+        let mut result = mutstr::from("abc");
+        result.clear_and_dealloc();
+        assert_eq!(result.as_str(), "");
+        assert_eq!(result.capacity(), 0);
+
+        result.push("123");
+        assert_eq!(result.as_str(), "123");
+    }
+
+    #[test]
+    fn replace_with_fmt() {
+        let mut result = mutstr::with_capacity(8);
+        let ptr = result.ptr();
+        let before = allocations();
+        crate::replace_with_fmt!(result, "x={}", 42);
+        assert_eq!(allocations(), before);
+        assert_eq!(result.ptr(), ptr);
+        assert_eq!(result.as_str(), "x=42");
+    }
+
+    #[test]
+    fn retain() {
+        let mut result = mutstr::from("a1b2c3");
+        let before = allocations();
+        result.retain(|c| c.is_alphabetic());
+        assert_eq!(allocations(), before);
+        assert_eq!(result.as_str(), "abc");
+        assert_eq!(result.capacity(), 6);
+    }
+
+    #[test]
+    fn retain_panic() {
+        let mut result = mutstr::from("a1😀2b");
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            result.retain(|c| {
+                assert_ne!(c, 'b');
+                c != '1'
+            });
+        }));
+        assert!(panicked.is_err());
+        assert_eq!(result.as_str(), "a😀2b");
+    }
+
+    #[test]
+    fn replace_with_reuse() {
+        let mut result = mutstr::with_capacity(8);
+        let before = allocations();
+        for i in 0..100 {
+            result.replace_with(if i % 2 == 0 { "abc" } else { "abcde" });
+        }
+        assert_eq!(allocations() - before, 0);
+        assert_eq!(result.as_str(), "abcde");
+        assert_eq!(result.capacity(), 8);
+    }
+
+    #[test]
+    fn raw_parts() {
+        let mut result = mutstr::with_capacity(10);
+        result.push("abc");
+        let (ptr, size) = result.into_raw_parts();
+        let result = unsafe { mutstr::from_raw_parts(ptr, size) };
+        assert_eq!(result.capacity(), 10);
+        assert_eq!(result.as_str(), "abc");
+    }
+}
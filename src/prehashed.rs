@@ -0,0 +1,148 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::mutstr;
+
+/// A [`mutstr`] together with the precomputed hash of its data.
+///
+/// `Hash` only writes the cached value, so hash tables don't hash the data again on every probe.
+/// The `mutstr` is only accessible immutably, which keeps the cached hash valid.
+///
+/// ### Example
+/// ```
+/// use std::collections::HashSet;
+/// use ::mutstr::{mutstr, PreHashed};
+/// let mut result = HashSet::new();
+/// result.insert(PreHashed::new(mutstr::from("friend")));
+/// assert!(result.contains(&PreHashed::new(mutstr::from("friend"))));
+/// ```
+pub struct PreHashed {
+    value: mutstr,
+    hash: u64,
+}
+
+impl PreHashed {
+    /// Computes the hash of `value` with the `DefaultHasher` of the standard library and stores both.
+    ///
+    /// ### Example
+    /// ```
+    /// use ::mutstr::{mutstr, PreHashed};
+    /// let result = PreHashed::new(mutstr::from("abc"));
+    /// assert_eq!(result.get().as_str(), "abc");
+    /// ```
+    pub fn new(value: mutstr) -> Self {
+        let mut hasher = DefaultHasher::new();
+        value.as_str().hash(&mut hasher);
+        Self {
+            hash: hasher.finish(),
+            value,
+        }
+    }
+
+    /// Get the wrapped `mutstr`.
+    #[inline(always)]
+    pub fn get(&self) -> &mutstr {
+        &self.value
+    }
+
+    /// Get the cached hash.
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    /// use ::mutstr::{mutstr, PreHashed};
+    /// let result = PreHashed::new(mutstr::from("abc"));
+    ///
+    /// let mut hasher = DefaultHasher::new();
+    /// "abc".hash(&mut hasher);
+    /// assert_eq!(result.cached_hash(), hasher.finish());
+    /// ```
+    #[inline(always)]
+    pub fn cached_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Unwraps the `mutstr`, to be able to mutate it again.
+    #[inline(always)]
+    pub fn into_inner(self) -> mutstr {
+        self.value
+    }
+}
+
+impl From<mutstr> for PreHashed {
+    #[inline]
+    fn from(value: mutstr) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Hash for PreHashed {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl PartialEq for PreHashed {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.value.as_bytes() == other.value.as_bytes()
+    }
+}
+
+impl Eq for PreHashed {}
+
+impl fmt::Display for PreHashed {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl fmt::Debug for PreHashed {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreHashed")
+            .field("value", &self.value)
+            .field("hash", &self.hash)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod implementations {
+    use super::PreHashed;
+    use crate::mutstr;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn hash() {
+        let result = PreHashed::new(mutstr::from("abc123"));
+
+        let mut fresh = DefaultHasher::new();
+        "abc123".hash(&mut fresh);
+        assert_eq!(result.cached_hash(), fresh.finish());
+
+        let mut written = DefaultHasher::new();
+        result.hash(&mut written);
+        let mut expected = DefaultHasher::new();
+        expected.write_u64(fresh.finish());
+        assert_eq!(written.finish(), expected.finish());
+    }
+
+    #[test]
+    fn eq() {
+        let first = PreHashed::new(mutstr::from("abc"));
+        let second = PreHashed::new(mutstr::from("abc"));
+        let third = PreHashed::new(mutstr::from("abd"));
+        assert!(first == second);
+        assert!(first != third);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::mutstr;
+
+/// A resumable scanner over the chars of a [`mutstr`], tracking a byte cursor.
+///
+/// ### Example
+/// ```
+/// use ::mutstr::{mutstr, Scanner};
+/// let source = mutstr::from("abc123");
+/// let mut scanner = Scanner::new(&source);
+/// assert_eq!(scanner.consume_while(char::is_alphabetic), "abc");
+/// assert_eq!(scanner.consume_while(char::is_numeric), "123");
+/// assert!(scanner.is_at_end());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    source: &'a mutstr,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    /// Creates a scanner starting at the first byte of `source`.
+    #[inline]
+    pub fn new(source: &'a mutstr) -> Self {
+        Self {
+            source,
+            position: 0,
+        }
+    }
+
+    /// Get the byte offset of the cursor.
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Get the data after the cursor.
+    ///
+    /// ### Example
+    /// ```
+    /// use ::mutstr::{mutstr, Scanner};
+    /// let source = mutstr::from("abc");
+    /// let mut scanner = Scanner::new(&source);
+    /// scanner.next_char();
+    /// assert_eq!(scanner.remaining(), "bc");
+    /// ```
+    #[inline]
+    pub fn remaining(&self) -> &'a str {
+        &self.source.as_str()[self.position..]
+    }
+
+    /// Returns `true` if the cursor reached the end of the data.
+    #[inline]
+    pub fn is_at_end(&self) -> bool {
+        self.position == self.source.size()
+    }
+
+    /// Get the char after the cursor, without moving the cursor.
+    ///
+    /// ### Example
+    /// ```
+    /// use ::mutstr::{mutstr, Scanner};
+    /// let source = mutstr::from("😀");
+    /// let scanner = Scanner::new(&source);
+    /// assert_eq!(scanner.peek_char(), Some('😀'));
+    /// assert_eq!(scanner.position(), 0);
+    /// ```
+    #[inline]
+    pub fn peek_char(&self) -> Option<char> {
+        self.remaining().chars().next()
+    }
+
+    /// Get the char after the cursor and move the cursor behind it.
+    ///
+    /// ### Example
+    /// ```
+    /// use ::mutstr::{mutstr, Scanner};
+    /// let source = mutstr::from("😀a");
+    /// let mut scanner = Scanner::new(&source);
+    /// assert_eq!(scanner.next_char(), Some('😀'));
+    /// assert_eq!(scanner.position(), 4);
+    /// assert_eq!(scanner.next_char(), Some('a'));
+    /// assert_eq!(scanner.next_char(), None);
+    /// ```
+    #[inline]
+    pub fn next_char(&mut self) -> Option<char> {
+        let value = self.peek_char()?;
+        self.position += value.len_utf8();
+        Some(value)
+    }
+
+    /// Moves the cursor behind all chars matching `pred` and returns them.
+    ///
+    /// ### Example
+    /// ```
+    /// use ::mutstr::{mutstr, Scanner};
+    /// let source = mutstr::from("  abc");
+    /// let mut scanner = Scanner::new(&source);
+    /// assert_eq!(scanner.consume_while(char::is_whitespace), "  ");
+    /// assert_eq!(scanner.consume_while(char::is_whitespace), "");
+    /// assert_eq!(scanner.remaining(), "abc");
+    /// ```
+    pub fn consume_while<P>(&mut self, mut pred: P) -> &'a str
+    where
+        P: FnMut(char) -> bool,
+    {
+        let remaining = self.remaining();
+        let len = remaining
+            .find(|value: char| !pred(value))
+            .unwrap_or(remaining.len());
+        self.position += len;
+        &remaining[..len]
+    }
+}
@@ -15,6 +15,16 @@ impl<'de> Visitor<'de> for MutStrVisitor {
     {
         Ok(mutstr::from(v))
     }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match std::str::from_utf8(v) {
+            Ok(value) => Ok(mutstr::from(value)),
+            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Bytes(v), &self)),
+        }
+    }
 }
 
 impl<'de> serde::Deserialize<'de> for mutstr {
@@ -35,6 +45,25 @@ impl serde::Serialize for mutstr {
     }
 }
 
+impl mutstr {
+    /// Returns `true` if the JSON string `json` is serialized back byte for byte after deserializing it as `mutstr`.
+    ///
+    /// **Notice:** _Only available with the `serde` feature. Escapes that `serde_json` writes differently,
+    /// like `\u0061` for `a`, don't round-trip_.
+    ///
+    /// ### Example
+    /// ```
+    /// use mutstr::mutstr;
+    /// assert!(mutstr::json_round_trips(r#""a\"😀\nb""#).unwrap());
+    /// assert!(!mutstr::json_round_trips(r#""\u0061""#).unwrap());
+    /// assert!(mutstr::json_round_trips("not json").is_err());
+    /// ```
+    pub fn json_round_trips(json: &str) -> Result<bool, serde_json::Error> {
+        let value: mutstr = serde_json::from_str(json)?;
+        Ok(serde_json::to_string(&value)?.as_bytes() == json.as_bytes())
+    }
+}
+
 #[cfg(test)]
 mod serde_implementation {
     use super::mutstr;
@@ -51,4 +80,31 @@ mod serde_implementation {
         assert_eq!(result.name.as_str(), "Nick");
         assert_eq!(serde_json::to_string(&result).unwrap(), raw);
     }
+
+    #[test]
+    fn from_to_escaped() {
+        let raw = r#"{"name":"a\"😀\nb\\"}"#;
+        let result = serde_json::from_str::<MyStruct>(raw).unwrap();
+        assert_eq!(result.name.as_str(), "a\"😀\nb\\");
+        assert_eq!(serde_json::to_string(&result).unwrap().as_bytes(), raw.as_bytes());
+    }
+
+    #[test]
+    fn json_round_trips() {
+        assert!(mutstr::json_round_trips(r#""a\"😀\nb\\""#).unwrap());
+        assert!(mutstr::json_round_trips(r#""""#).unwrap());
+        assert!(!mutstr::json_round_trips(r#""\ud83d\ude00""#).unwrap());
+        assert!(mutstr::json_round_trips("1").is_err());
+    }
+
+    #[test]
+    fn from_bytes() {
+        use serde::de::{value::BytesDeserializer, value::Error, Deserialize};
+
+        let valid = BytesDeserializer::<Error>::new("a😀".as_bytes());
+        assert_eq!(mutstr::deserialize(valid).unwrap().as_str(), "a😀");
+
+        let invalid = BytesDeserializer::<Error>::new(&[0x61, 0xff]);
+        assert!(mutstr::deserialize(invalid).is_err());
+    }
 }
\ No newline at end of file